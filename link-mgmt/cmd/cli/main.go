@@ -1,13 +1,21 @@
 package main
 
 import (
+	"errors"
 	"flag"
 	"fmt"
+	"io"
 	"log"
+	"net"
+	"net/http"
+	"net/url"
 	"os"
+	"strconv"
 	"strings"
 
 	"link-mgmt/pkg/cli"
+	"link-mgmt/pkg/cli/client"
+	"link-mgmt/pkg/cli/links"
 	"link-mgmt/pkg/config"
 	"link-mgmt/pkg/scraper"
 	"link-mgmt/pkg/utils"
@@ -18,12 +26,95 @@ func main() {
 		register  = flag.String("register", "", "Register a new user account (provide email)")
 		scrapeURL = flag.String("scrape", "", "Scrape a URL to extract title and text content")
 		saveURL   = flag.String("save", "", "Save a link to the API (provide URL)")
+		saveJSON  = flag.Bool("save-json", false, "Save a link from a JSON object read from stdin")
+		extract   = flag.String("extract", "", "Save every link found in a Markdown or HTML file")
+
+		// Save fields
+		description      = flag.String("description", "", "Description for --save")
+		descriptionStdin = flag.Bool("description-stdin", false, "Read the description for --save from stdin")
+		titleMaxLen      = flag.Int("title-max-len", 0, "Truncate saved titles to N characters at a word boundary; 0 means no limit (default from cli.title_max_len)")
+		descMaxLen       = flag.Int("description-max-len", 0, "Truncate saved descriptions to N characters at a word boundary; 0 means no limit (default from cli.description_max_len)")
+
+		// Auth commands
+		authVerify = flag.Bool("auth-verify", false, "Check that the stored API key is valid (exit 0 valid, 1 invalid or other error, 2 API unreachable)")
+
+		// Save checks
+		verify      = flag.Bool("verify", false, "Check that the URL responds before saving (default from cli.verify_on_save)")
+		noVerify    = flag.Bool("no-verify", false, "Skip the pre-save URL check even if cli.verify_on_save is set")
+		force       = flag.Bool("force", false, "Save even if the pre-save URL check fails, without asking")
+		ifReachable = flag.Bool("if-reachable", false, "Skip saving, with a warning, if the URL returns 4xx/5xx or cannot be reached")
+
+		// Prompt behavior
+		noInteractive = flag.Bool("no-interactive", false, "Never prompt when saving, even on a terminal (also LINK_MGMT_NONINTERACTIVE=1)")
+		interactive   = flag.Bool("interactive", false, "Prompt when saving even if stdin is not a terminal, reading answers from /dev/tty")
+
+		// Report commands
+		topDomains = flag.Int("top-domains", 0, "Show the N most common domains across your links")
+		recent     = flag.Int("recent", 0, "Show the N most recently saved links")
+		format     = flag.String("format", "table", "Output format for --top-domains (table, csv), --recent (table, json, rss) and --auth-verify (table, json)")
+
+		// Maintenance commands
+		fillTitles   = flag.Bool("fill-titles", false, "Fetch and set titles for links that have none")
+		resolveTitle = flag.String("resolve-title", "", "Refresh a link's title from the live page (provide link ID)")
+		dryRun       = flag.Bool("dry-run", false, "Show proposed changes without saving them")
+		confirmEach  = flag.Bool("confirm-each", false, "Ask before applying each change")
+
+		// Raw API access
+		apiRequest = flag.String("api", "", "Send a raw request to the API (format: 'METHOD /path')")
+		apiData    = flag.String("data", "", "JSON body for --api, inline or @file")
+
+		// Request behavior
+		retry = flag.Bool("retry", false, "Retry rate-limited (429) API requests up to cli.retries times")
+
+		// Output
+		ascii = flag.Bool("ascii", false, "Print ASCII markers (OK, X, !, -) instead of unicode symbols, for consoles that cannot display them")
 
 		// Config commands
-		configShow = flag.Bool("config-show", false, "Show current configuration")
-		configSet  = flag.String("config-set", "", "Set a config value (format: section.key=value)")
+		configShow     = flag.Bool("config-show", false, "Show current configuration")
+		configSet      = flag.String("config-set", "", "Set a config value (format: section.key=value)")
+		configEdit     = flag.Bool("config-edit", false, "Open the config file in $EDITOR")
+		configValidate = flag.Bool("config-validate", false, "Check the config file for errors and unknown keys")
+		configReset    = flag.Bool("config-reset", false, "Restore the config file to defaults, keeping the API key")
+		keepBaseURL    = flag.Bool("keep-base-url", false, "Keep cli.base_url when using --config-reset")
+		yes            = flag.Bool("yes", false, "Skip the confirmation prompt for --config-reset")
 	)
 	flag.Parse()
+	links.SetASCII(*ascii)
+
+	// Config edit runs before loading so a broken config file can still be fixed
+	if *configEdit {
+		if err := cli.EditConfig(); err != nil {
+			log.Fatalf("failed to edit config: %v", err)
+		}
+		fmt.Println("Configuration updated successfully")
+		return
+	}
+
+	// Config validate also runs before loading so it can report a broken config file
+	if *configValidate {
+		valid, err := cli.ValidateConfig()
+		if err != nil {
+			log.Fatalf("failed to validate config: %v", err)
+		}
+		if !valid {
+			os.Exit(1)
+		}
+		return
+	}
+
+	// Config reset also runs before loading and rewrites the file directly
+	if *configReset {
+		reset, err := cli.ResetConfig(*keepBaseURL, *yes)
+		if err != nil {
+			log.Fatalf("failed to reset config: %v", err)
+		}
+		if !reset {
+			fmt.Println("Reset cancelled")
+			return
+		}
+		fmt.Println("Configuration reset to defaults")
+		return
+	}
 
 	cfg, err := config.Load()
 	if err != nil {
@@ -31,6 +122,42 @@ func main() {
 	}
 
 	app := cli.NewApp(cfg)
+	if *retry {
+		app.EnableRetries()
+	}
+
+	if *interactive && *noInteractive {
+		log.Fatalf("--interactive and --no-interactive cannot be combined")
+	}
+	interactivity := cli.InteractiveAuto
+	if nonInteractive, err := strconv.ParseBool(os.Getenv("LINK_MGMT_NONINTERACTIVE")); err == nil && nonInteractive {
+		interactivity = cli.InteractiveNever
+	}
+	if *noInteractive {
+		interactivity = cli.InteractiveNever
+	}
+	if *interactive {
+		interactivity = cli.InteractiveAlways
+	}
+
+	saveOpts := cli.SaveOptions{
+		Verify:        (cfg.CLI.VerifyOnSave || *verify) && !*noVerify,
+		Force:         *force,
+		IfReachable:   *ifReachable,
+		Interactivity: interactivity,
+
+		TitleMaxLen:       cfg.CLI.TitleMaxLen,
+		DescriptionMaxLen: cfg.CLI.DescriptionMaxLen,
+	}
+	// The length flags override the config only when given, since 0 means no limit
+	flag.Visit(func(f *flag.Flag) {
+		switch f.Name {
+		case "title-max-len":
+			saveOpts.TitleMaxLen = *titleMaxLen
+		case "description-max-len":
+			saveOpts.DescriptionMaxLen = *descMaxLen
+		}
+	})
 
 	// Handle config commands first (don't need API connection)
 	if *configShow {
@@ -51,7 +178,25 @@ func main() {
 			log.Fatalf("Base URL not configured. Set it with: --config-set cli.base_url=<url>")
 		}
 		if err := app.RegisterUser(*register); err != nil {
-			log.Fatalf("failed to register user: %v", err)
+			fatalf("failed to register user: %v", err)
+		}
+		return
+	}
+
+	// Handle auth verify (read-only, exit code reports the result)
+	if *authVerify {
+		if cfg.CLI.BaseURL == "" {
+			log.Fatalf("Base URL not configured. Set it with: --config-set cli.base_url=<url>")
+		}
+		if *format != "table" && *format != "json" {
+			log.Fatalf("unknown --format %q for --auth-verify (expected table or json)", *format)
+		}
+		valid, err := app.VerifyAuth(*format)
+		if err != nil {
+			fatalf("failed to verify API key: %v", err)
+		}
+		if !valid {
+			os.Exit(exitError)
 		}
 		return
 	}
@@ -73,30 +218,15 @@ func main() {
 		scraperService := scraper.NewScraperService(cfg.CLI.BaseURL)
 
 		// Check health first
-		fmt.Print("⏳ Checking scraper service... ")
+		fmt.Print(links.Markers("⏳ Checking scraper service... "))
 		if err := scraperService.CheckHealth(); err != nil {
-			fmt.Println("✗")
-
-			// Provide helpful guidance for connection errors
-			errStr := err.Error()
-			if strings.Contains(errStr, "connection refused") || strings.Contains(errStr, "dial tcp") {
-				log.Fatalf("scraper service unavailable: %v\n\n"+
-					"💡 The services are not running. To start them:\n"+
-					"   From project root: make dev-upd\n"+
-					"   Or: docker compose --profile dev up -d --build\n\n"+
-					"This will start:\n"+
-					"  - Nginx reverse proxy (port 80)\n"+
-					"  - API service (api-dev)\n"+
-					"  - Scraper service (scraper-dev)\n"+
-					"  - PostgreSQL database", err)
-			}
-
-			log.Fatalf("scraper service unavailable: %v\n\nPlease check if the service is running", err)
+			fmt.Println(links.Markers("✗"))
+			log.Fatalf("scraper service unavailable: %v", app.ExplainConnectionError(err))
 		}
-		fmt.Println("✓")
+		fmt.Println(links.Markers("✓"))
 
 		// Scrape the URL
-		fmt.Printf("⏳ Scraping URL... (this may take a few seconds)\n")
+		fmt.Print(links.Markers("⏳ Scraping URL... (this may take a few seconds)\n"))
 		timeout := cfg.CLI.ScrapeTimeout
 		if timeout <= 0 {
 			timeout = 30
@@ -113,15 +243,19 @@ func main() {
 		}
 
 		// Display results
-		fmt.Println("\n✓ Scraping successful!")
-		fmt.Printf("\nURL: %s\n", result.URL)
+		fmt.Println(links.Markers("\n✓ Scraping successful!"))
+		// Scraped values come from an arbitrary page, so strip terminal escapes before printing
+		fmt.Printf("\nURL: %s\n", utils.SanitizeLine(result.URL))
 		if result.Title != "" {
-			fmt.Printf("Title: %s\n", result.Title)
+			fmt.Printf("Title: %s\n", utils.SanitizeLine(result.Title))
 		} else {
 			fmt.Println("Title: (no title)")
 		}
+		if result.Description != "" {
+			fmt.Printf("Description: %s\n", utils.SanitizeForTerminal(result.Description))
+		}
 		if result.Text != "" {
-			truncated := truncateText(result.Text, 500)
+			truncated := utils.SanitizeForTerminal(truncateText(result.Text, 500))
 			fmt.Printf("Text: %s\n", truncated)
 			if len(result.Text) > 500 {
 				fmt.Printf("\n(Text truncated, full length: %d characters)\n", len(result.Text))
@@ -132,6 +266,30 @@ func main() {
 		return
 	}
 
+	// Handle save-json command (needs base URL and API key)
+	if *saveJSON {
+		if *saveURL != "" {
+			log.Fatalf("--save-json reads the URL from stdin and cannot be combined with --save <url>")
+		}
+		if *description != "" || *descriptionStdin {
+			log.Fatalf("--save-json takes the description from the JSON object")
+		}
+		if *interactive {
+			log.Fatalf("--save-json reads the link from stdin and cannot prompt; drop --interactive (use --force or --if-reachable to decide without asking)")
+		}
+		if cfg.CLI.BaseURL == "" {
+			log.Fatalf("Base URL not configured. Set it with: --config-set cli.base_url=<url>")
+		}
+		if cfg.CLI.APIKey == "" {
+			log.Fatalf("API key not configured. Register a user with --register <email> or set it with: --config-set cli.api_key=<key>")
+		}
+
+		if err := app.SaveLinkFromJSON(os.Stdin, saveOpts); err != nil {
+			fatalf("failed to save link: %v", err)
+		}
+		return
+	}
+
 	// Handle save command (needs base URL and API key)
 	if *saveURL != "" {
 		if cfg.CLI.BaseURL == "" {
@@ -148,8 +306,81 @@ func main() {
 			os.Exit(1)
 		}
 
-		if err := app.SaveLink(urlStr); err != nil {
-			log.Fatalf("failed to save link: %v", err)
+		desc := *description
+		if *descriptionStdin {
+			if desc != "" {
+				log.Fatalf("--description and --description-stdin cannot be combined")
+			}
+			data, err := io.ReadAll(os.Stdin)
+			if err != nil {
+				log.Fatalf("failed to read description from stdin: %v", err)
+			}
+			desc = strings.TrimSpace(string(data))
+		}
+
+		if err := app.SaveLink(urlStr, desc, saveOpts); err != nil {
+			fatalf("failed to save link: %v", err)
+		}
+		return
+	}
+
+	// Handle extract command (needs base URL and API key)
+	if *extract != "" {
+		if !*dryRun {
+			if cfg.CLI.BaseURL == "" {
+				log.Fatalf("Base URL not configured. Set it with: --config-set cli.base_url=<url>")
+			}
+			if cfg.CLI.APIKey == "" {
+				log.Fatalf("API key not configured. Register a user with --register <email> or set it with: --config-set cli.api_key=<key>")
+			}
+		}
+
+		if err := app.SaveExtractedLinks(*extract, *dryRun, saveOpts); err != nil {
+			fatalf("failed to save extracted links: %v", err)
+		}
+		return
+	}
+
+	// Handle top-domains command (needs base URL and API key)
+	if *topDomains > 0 {
+		if err := app.ShowTopDomains(*topDomains, *format); err != nil {
+			fatalf("failed to show top domains: %v", err)
+		}
+		return
+	}
+
+	// Handle recent command (needs base URL and API key)
+	if *recent > 0 {
+		if err := app.ShowRecentLinks(*recent, *format); err != nil {
+			fatalf("failed to show recent links: %v", err)
+		}
+		return
+	}
+
+	// Handle fill-titles command (needs base URL and API key)
+	if *fillTitles {
+		if err := app.FillMissingTitles(*dryRun, *confirmEach, interactivity, saveOpts.TitleMaxLen); err != nil {
+			fatalf("failed to fill titles: %v", err)
+		}
+		return
+	}
+
+	// Handle raw API request (needs base URL and API key)
+	if *apiRequest != "" {
+		status, err := app.CallAPI(*apiRequest, *apiData)
+		if err != nil {
+			fatalf("API request failed: %v", err)
+		}
+		if status < 200 || status >= 300 {
+			os.Exit(exitCodeForStatus(status))
+		}
+		return
+	}
+
+	// Handle resolve-title command (needs base URL and API key)
+	if *resolveTitle != "" {
+		if err := app.ResolveTitle(*resolveTitle, *dryRun, saveOpts.TitleMaxLen); err != nil {
+			fatalf("failed to resolve title: %v", err)
 		}
 		return
 	}
@@ -168,3 +399,47 @@ func truncateText(text string, maxLen int) string {
 	}
 	return text[:maxLen] + "..."
 }
+
+// Exit codes used by fatalf so scripts can tell failures apart
+const (
+	exitError        = 1 // Any other failure
+	exitNetwork      = 2 // The API could not be reached
+	exitUnauthorized = 3 // The API key is missing, invalid or expired
+	exitRateLimited  = 4 // The API rate limited the request
+	exitNotFound     = 5 // The requested link does not exist
+)
+
+// fatalf logs the error like log.Fatalf and exits with a code describing it
+func fatalf(format string, err error) {
+	log.Printf(format, err)
+	var (
+		rateErr *client.RateLimitError
+		urlErr  *url.Error
+		opErr   *net.OpError
+	)
+	switch {
+	case errors.As(err, &rateErr):
+		os.Exit(exitRateLimited)
+	case errors.Is(err, client.ErrUnauthorized):
+		os.Exit(exitUnauthorized)
+	case errors.Is(err, client.ErrNotFound):
+		os.Exit(exitNotFound)
+	case errors.As(err, &urlErr), errors.As(err, &opErr):
+		os.Exit(exitNetwork)
+	}
+	os.Exit(exitError)
+}
+
+// exitCodeForStatus maps a non-2xx HTTP status to the exit code fatalf uses for
+// the matching client error
+func exitCodeForStatus(status int) int {
+	switch status {
+	case http.StatusTooManyRequests:
+		return exitRateLimited
+	case http.StatusUnauthorized, http.StatusForbidden:
+		return exitUnauthorized
+	case http.StatusNotFound:
+		return exitNotFound
+	}
+	return exitError
+}
@@ -0,0 +1,87 @@
+package cli
+
+import (
+	"bytes"
+	"encoding/json"
+	"fmt"
+	"io"
+	"net/http"
+	"net/url"
+	"os"
+	"strings"
+)
+
+// CallAPI sends a raw authenticated request to the API and prints the response body.
+// request has the form "METHOD /path"; data is an inline JSON body or @file to read
+// it from. Returns the HTTP status code of the response.
+func (a *App) CallAPI(request, data string) (int, error) {
+	fields := strings.Fields(request)
+	if len(fields) != 2 {
+		return 0, fmt.Errorf("invalid request %q: expected 'METHOD /path'", request)
+	}
+	method := strings.ToUpper(fields[0])
+
+	// Only paths under the configured base URL are allowed, so the API key is
+	// never sent to another host
+	path, ok := relativeAPIPath(fields[1])
+	if !ok {
+		return 0, fmt.Errorf("path must be relative to the base URL (%s), got %q", a.cfg.CLI.BaseURL, fields[1])
+	}
+
+	var body io.Reader
+	if data != "" {
+		payload := []byte(data)
+		if strings.HasPrefix(data, "@") {
+			fileData, err := os.ReadFile(strings.TrimPrefix(data, "@"))
+			if err != nil {
+				return 0, fmt.Errorf("failed to read request body: %w", err)
+			}
+			payload = fileData
+		}
+		if !json.Valid(payload) {
+			return 0, fmt.Errorf("request body is not valid JSON")
+		}
+		body = bytes.NewReader(payload)
+	}
+
+	apiClient, err := a.getClient()
+	if err != nil {
+		return 0, fmt.Errorf("failed to create API client: %w", err)
+	}
+
+	status, respBody, err := apiClient.DoRaw(method, path, body)
+	if err != nil {
+		return status, a.ExplainConnectionError(err)
+	}
+
+	fmt.Fprintf(os.Stderr, "HTTP %d %s\n", status, http.StatusText(status))
+
+	// Pretty-print JSON for humans, pass the body through untouched for pipes
+	if isTerminal(os.Stdout) && json.Valid(respBody) {
+		var pretty bytes.Buffer
+		if err := json.Indent(&pretty, respBody, "", "  "); err == nil {
+			respBody = pretty.Bytes()
+		}
+	}
+	if _, err := os.Stdout.Write(respBody); err != nil {
+		return status, err
+	}
+	if len(respBody) > 0 && respBody[len(respBody)-1] != '\n' && isTerminal(os.Stdout) {
+		fmt.Println()
+	}
+
+	return status, nil
+}
+
+// relativeAPIPath returns path with a leading slash, or false if it names a
+// scheme or host of its own. URLs in the query string are allowed.
+func relativeAPIPath(path string) (string, bool) {
+	u, err := url.Parse(path)
+	if err != nil || u.Scheme != "" || u.Host != "" {
+		return "", false
+	}
+	if !strings.HasPrefix(path, "/") {
+		path = "/" + path
+	}
+	return path, true
+}
@@ -0,0 +1,28 @@
+package cli
+
+import "testing"
+
+func TestRelativeAPIPath(t *testing.T) {
+	tests := []struct {
+		path   string
+		want   string
+		wantOK bool
+	}{
+		{path: "/api/v1/links", want: "/api/v1/links", wantOK: true},
+		{path: "api/v1/links", want: "/api/v1/links", wantOK: true},
+		{path: "/api/v1/links?url=https://example.com", want: "/api/v1/links?url=https://example.com", wantOK: true},
+		{path: "https://example.com/api/v1/links"},
+		{path: "//example.com/api/v1/links"},
+		{path: "example.com:8080/api/v1/links"},
+		{path: "/api/%zz"},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.path, func(t *testing.T) {
+			got, ok := relativeAPIPath(tt.path)
+			if ok != tt.wantOK || got != tt.want {
+				t.Errorf("relativeAPIPath(%q) = %q, %v; want %q, %v", tt.path, got, ok, tt.want, tt.wantOK)
+			}
+		})
+	}
+}
@@ -1,19 +1,40 @@
 package cli
 
 import (
+	"encoding/json"
+	"errors"
 	"fmt"
+	"io"
+	"net"
+	"os"
+	"sort"
+	"strings"
 
 	tea "github.com/charmbracelet/bubbletea"
+	"github.com/google/uuid"
 
 	"link-mgmt/pkg/cli/client"
+	"link-mgmt/pkg/cli/links"
 	"link-mgmt/pkg/cli/tui"
 	"link-mgmt/pkg/config"
 	"link-mgmt/pkg/models"
+	"link-mgmt/pkg/utils"
 )
 
 type App struct {
-	cfg    *config.Config
-	client *client.Client
+	cfg     *config.Config
+	client  *client.Client
+	api     linkAPI // Replaces the HTTP client in tests
+	retries int
+}
+
+// linkAPI is the part of the API client the commands use
+type linkAPI interface {
+	ListLinks() ([]models.Link, error)
+	GetLink(id uuid.UUID) (*models.Link, error)
+	CreateLink(link models.LinkCreate) (*models.Link, error)
+	UpdateLink(id uuid.UUID, update models.LinkUpdate) (*models.Link, error)
+	GetMe() (*models.User, error)
 }
 
 func NewApp(cfg *config.Config) *App {
@@ -22,6 +43,15 @@ func NewApp(cfg *config.Config) *App {
 	}
 }
 
+// EnableRetries makes API requests retry rate-limited (429) responses up to
+// cli.retries times, waiting for the server's Retry-After between attempts
+func (a *App) EnableRetries() {
+	a.retries = a.cfg.CLI.Retries
+	if a.client != nil {
+		a.client.SetMaxRetries(a.retries)
+	}
+}
+
 // getClient returns the HTTP client, creating it if necessary
 func (a *App) getClient() (*client.Client, error) {
 	if a.client != nil {
@@ -36,9 +66,22 @@ func (a *App) getClient() (*client.Client, error) {
 	}
 
 	a.client = client.NewClient(a.cfg.CLI.BaseURL, a.cfg.CLI.APIKey)
+	a.client.SetMaxRetries(a.retries)
 	return a.client, nil
 }
 
+// getAPI returns the client commands talk to: the API set by tests, or the HTTP client
+func (a *App) getAPI() (linkAPI, error) {
+	if a.api != nil {
+		return a.api, nil
+	}
+	apiClient, err := a.getClient()
+	if err != nil {
+		return nil, err
+	}
+	return apiClient, nil
+}
+
 // getClientForRegistration returns an HTTP client without API key (for registration)
 func (a *App) getClientForRegistration() (*client.Client, error) {
 	if a.cfg.CLI.BaseURL == "" {
@@ -48,26 +91,133 @@ func (a *App) getClientForRegistration() (*client.Client, error) {
 	return client.NewClient(a.cfg.CLI.BaseURL, ""), nil
 }
 
-// SaveLink saves a link to the API
-func (a *App) SaveLink(url string) error {
-	apiClient, err := a.getClient()
-	if err != nil {
-		return fmt.Errorf("failed to create API client: %w", err)
+// ExplainConnectionError adds setup guidance when a request could not connect,
+// naming where the base URL came from so that an explicitly configured server
+// is not mistaken for the built-in default
+func (a *App) ExplainConnectionError(err error) error {
+	var opErr *net.OpError
+	if err == nil || !errors.As(err, &opErr) {
+		return err
+	}
+	if a.cfg.CLI.BaseURLSource == config.SourceDefault {
+		return fmt.Errorf("%w\n\n"+
+			symbol("💡 Nothing is running at the default base URL %s.\n")+
+			"   Start the services from the project root: make upd\n"+
+			"   Or point the CLI at another server: --config-set cli.base_url=<url>", err, a.cfg.CLI.BaseURL)
 	}
+	return fmt.Errorf("%w\n\n"+
+		symbol("💡 Nothing is running at %s (cli.base_url, from the %s).\n")+
+		"   If it is this project's stack, start it from the project root: make upd\n"+
+		"   Otherwise check the URL with --config-show", err, a.cfg.CLI.BaseURL, a.cfg.CLI.BaseURLSource)
+}
+
+// SaveOptions controls the checks done before a link is saved
+type SaveOptions struct {
+	Verify        bool          // Check that the URL responds before saving it
+	Force         bool          // Save even if verification fails, without asking
+	IfReachable   bool          // Skip the save with a warning if the URL fails to respond
+	Interactivity Interactivity // Whether verification may ask before saving
 
+	TitleMaxLen       int // Truncate the title at a word boundary; 0 means no limit
+	DescriptionMaxLen int // Truncate the description at a word boundary; 0 means no limit
+}
+
+// SaveLink saves a link to the API, with an optional description
+func (a *App) SaveLink(url, description string, opts SaveOptions) error {
 	linkCreate := models.LinkCreate{
 		URL: url,
 	}
+	if description != "" {
+		linkCreate.Description = &description
+	}
+	return a.saveLink(linkCreate, opts)
+}
+
+// linkJSONFields are the keys SaveLinkFromJSON reads; others are ignored with a warning
+var linkJSONFields = map[string]bool{"url": true, "title": true, "description": true, "text": true}
+
+// SaveLinkFromJSON saves a link described by a single JSON object, e.g.
+// {"url": "...", "title": "...", "description": "..."}. Since the object is
+// read from stdin, save prompts are never shown.
+func (a *App) SaveLinkFromJSON(r io.Reader, opts SaveOptions) error {
+	var raw json.RawMessage
+	if err := json.NewDecoder(r).Decode(&raw); err != nil {
+		return fmt.Errorf("invalid link JSON: %w", err)
+	}
+
+	var fields map[string]json.RawMessage
+	if err := json.Unmarshal(raw, &fields); err != nil {
+		return fmt.Errorf("invalid link JSON: %w", err)
+	}
+	var unknown []string
+	for key := range fields {
+		if !linkJSONFields[strings.ToLower(key)] {
+			unknown = append(unknown, key)
+		}
+	}
+	if len(unknown) > 0 {
+		sort.Strings(unknown)
+		fmt.Fprintf(os.Stderr, symbol("⚠️  Ignoring unknown field(s) in link JSON: %s\n"), strings.Join(unknown, ", "))
+	}
+
+	var linkCreate models.LinkCreate
+	if err := json.Unmarshal(raw, &linkCreate); err != nil {
+		return fmt.Errorf("invalid link JSON: %w", err)
+	}
+
+	urlStr, err := utils.ValidateURL(linkCreate.URL)
+	if err != nil {
+		return fmt.Errorf("invalid link JSON: %w", err)
+	}
+	linkCreate.URL = urlStr
+
+	opts.Interactivity = InteractiveNever
+	return a.saveLink(linkCreate, opts)
+}
+
+// saveLink creates the link and prints a summary of what was saved
+func (a *App) saveLink(linkCreate models.LinkCreate, opts SaveOptions) error {
+	apiClient, err := a.getAPI()
+	if err != nil {
+		return fmt.Errorf("failed to create API client: %w", err)
+	}
+
+	if linkCreate.Title != nil {
+		title := utils.TruncateAtWordBoundary(*linkCreate.Title, opts.TitleMaxLen)
+		linkCreate.Title = &title
+	}
+	if linkCreate.Description != nil {
+		description := utils.TruncateAtWordBoundary(*linkCreate.Description, opts.DescriptionMaxLen)
+		linkCreate.Description = &description
+	}
+
+	if opts.IfReachable {
+		if result := checkURL(linkCreate.URL); decideVerifyAction(linkCreate.URL, result) == verifyConfirm {
+			fmt.Fprintf(os.Stderr, symbol("⚠️  Skipped %s: %s\n"), linkCreate.URL, result.describe())
+			return nil
+		}
+	}
+
+	if opts.Verify {
+		urlToSave, err := a.verifyBeforeSave(linkCreate.URL, opts)
+		if err != nil {
+			return err
+		}
+		linkCreate.URL = urlToSave
+	}
 
 	created, err := apiClient.CreateLink(linkCreate)
 	if err != nil {
-		return fmt.Errorf("failed to save link: %w", err)
+		return fmt.Errorf("failed to save link: %w", a.ExplainConnectionError(err))
 	}
 
-	fmt.Println("✓ Link saved successfully!")
-	fmt.Printf("  URL: %s\n", created.URL)
+	fmt.Println(symbol("✓ Link saved successfully!"))
+	fmt.Printf("  URL: %s\n", utils.SanitizeLine(created.URL))
 	if created.Title != nil && *created.Title != "" {
-		fmt.Printf("  Title: %s\n", *created.Title)
+		fmt.Printf("  Title: %s\n", utils.SanitizeLine(*created.Title))
+	}
+	if created.Description != nil && *created.Description != "" {
+		fmt.Printf("  Description: %s\n", utils.SanitizeForTerminal(*created.Description))
 	}
 	fmt.Printf("  ID: %s\n", created.ID.String())
 
@@ -86,3 +236,8 @@ func (a *App) Run() error {
 	_, err = p.Run()
 	return err
 }
+
+// symbol returns s, or its ASCII replacement when --ascii is set
+func symbol(s string) string {
+	return links.Markers(s)
+}
@@ -0,0 +1,381 @@
+package cli
+
+import (
+	"encoding/json"
+	"errors"
+	"io"
+	"net/http"
+	"net/http/httptest"
+	"net/url"
+	"os"
+	"strings"
+	"testing"
+	"time"
+
+	"github.com/google/uuid"
+
+	"link-mgmt/pkg/cli/client"
+	"link-mgmt/pkg/config"
+	"link-mgmt/pkg/models"
+)
+
+// fakeAPI is an in-memory linkAPI. Errors set on it are returned by the
+// matching method; created and updated links are recorded for assertions.
+type fakeAPI struct {
+	links   []models.Link
+	user    *models.User
+	listErr error
+	meErr   error
+
+	created []models.LinkCreate
+	updated map[uuid.UUID]models.LinkUpdate
+}
+
+func (f *fakeAPI) ListLinks() ([]models.Link, error) {
+	if f.listErr != nil {
+		return nil, f.listErr
+	}
+	return append([]models.Link(nil), f.links...), nil
+}
+
+func (f *fakeAPI) GetLink(id uuid.UUID) (*models.Link, error) {
+	for _, link := range f.links {
+		if link.ID == id {
+			return &link, nil
+		}
+	}
+	return nil, &client.APIError{StatusCode: http.StatusNotFound, Message: "link not found"}
+}
+
+func (f *fakeAPI) CreateLink(link models.LinkCreate) (*models.Link, error) {
+	f.created = append(f.created, link)
+	created := models.Link{
+		ID:          uuid.New(),
+		URL:         link.URL,
+		Title:       link.Title,
+		Description: link.Description,
+		Text:        link.Text,
+		CreatedAt:   time.Now(),
+	}
+	f.links = append(f.links, created)
+	return &created, nil
+}
+
+func (f *fakeAPI) UpdateLink(id uuid.UUID, update models.LinkUpdate) (*models.Link, error) {
+	if f.updated == nil {
+		f.updated = make(map[uuid.UUID]models.LinkUpdate)
+	}
+	f.updated[id] = update
+	return f.GetLink(id)
+}
+
+func (f *fakeAPI) GetMe() (*models.User, error) {
+	if f.meErr != nil {
+		return nil, f.meErr
+	}
+	return f.user, nil
+}
+
+// newTestApp returns an App with default config and an API key that talks to api
+func newTestApp(api linkAPI) *App {
+	cfg := config.DefaultConfig()
+	cfg.CLI.APIKey = "test-key-1234"
+	return &App{cfg: cfg, api: api}
+}
+
+// captureStdout returns what fn writes to os.Stdout
+func captureStdout(t *testing.T, fn func()) string {
+	t.Helper()
+	r, w, err := os.Pipe()
+	if err != nil {
+		t.Fatal(err)
+	}
+	done := make(chan string)
+	go func() {
+		out, _ := io.ReadAll(r)
+		done <- string(out)
+	}()
+
+	orig := os.Stdout
+	os.Stdout = w
+	defer func() { os.Stdout = orig }()
+	fn()
+	w.Close()
+	return <-done
+}
+
+func linkAt(url string, created time.Time) models.Link {
+	return models.Link{ID: uuid.New(), URL: url, CreatedAt: created}
+}
+
+func TestShowRecentLinksSortsAndLimits(t *testing.T) {
+	base := time.Date(2026, time.March, 1, 12, 0, 0, 0, time.UTC)
+	api := &fakeAPI{links: []models.Link{
+		linkAt("https://example.com/old", base),
+		linkAt("https://example.com/newest", base.Add(2*time.Hour)),
+		linkAt("https://example.com/middle", base.Add(time.Hour)),
+	}}
+	app := newTestApp(api)
+
+	var err error
+	out := captureStdout(t, func() { err = app.ShowRecentLinks(2, "json") })
+	if err != nil {
+		t.Fatalf("ShowRecentLinks() error = %v", err)
+	}
+
+	var got []models.Link
+	if err := json.Unmarshal([]byte(out), &got); err != nil {
+		t.Fatalf("output is not JSON: %v\n%s", err, out)
+	}
+	want := []string{"https://example.com/newest", "https://example.com/middle"}
+	if len(got) != len(want) {
+		t.Fatalf("got %d links, want %d", len(got), len(want))
+	}
+	for i := range want {
+		if got[i].URL != want[i] {
+			t.Errorf("link %d = %s, want %s", i, got[i].URL, want[i])
+		}
+	}
+}
+
+func TestShowRecentLinksEmptyTable(t *testing.T) {
+	app := newTestApp(&fakeAPI{})
+
+	var err error
+	out := captureStdout(t, func() { err = app.ShowRecentLinks(10, "table") })
+	if err != nil {
+		t.Fatalf("ShowRecentLinks() error = %v", err)
+	}
+	if !strings.Contains(out, "No links found.") {
+		t.Errorf("output = %q, want the empty message", out)
+	}
+}
+
+func TestShowRecentLinksRejectsUnknownFormat(t *testing.T) {
+	app := newTestApp(&fakeAPI{})
+	if err := app.ShowRecentLinks(10, "csv"); err == nil {
+		t.Error("ShowRecentLinks() with format csv succeeded, want an error")
+	}
+}
+
+func TestVerifyAuth(t *testing.T) {
+	tests := []struct {
+		name      string
+		api       *fakeAPI
+		wantValid bool
+		wantErr   bool
+		wantJSON  authStatus
+	}{
+		{
+			name:      "valid key",
+			api:       &fakeAPI{user: &models.User{Email: "me@example.com"}},
+			wantValid: true,
+			wantJSON: authStatus{
+				Authenticated: true,
+				Email:         "me@example.com",
+				APIKeyPrefix:  "test****",
+				BaseURL:       "http://localhost",
+			},
+		},
+		{
+			name: "401 is not authenticated",
+			api:  &fakeAPI{meErr: &client.APIError{StatusCode: http.StatusUnauthorized}},
+		},
+		{
+			name: "403 is not authenticated",
+			api:  &fakeAPI{meErr: &client.APIError{StatusCode: http.StatusForbidden}},
+		},
+		{
+			name:    "500 is an error",
+			api:     &fakeAPI{meErr: &client.APIError{StatusCode: http.StatusInternalServerError}},
+			wantErr: true,
+		},
+		{
+			name:    "network failure is an error",
+			api:     &fakeAPI{meErr: errors.New("request failed: connection refused")},
+			wantErr: true,
+		},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			app := newTestApp(tt.api)
+
+			var (
+				valid bool
+				err   error
+			)
+			out := captureStdout(t, func() { valid, err = app.VerifyAuth("json") })
+			if (err != nil) != tt.wantErr {
+				t.Fatalf("VerifyAuth() error = %v, wantErr %v", err, tt.wantErr)
+			}
+			if valid != tt.wantValid {
+				t.Errorf("VerifyAuth() = %v, want %v", valid, tt.wantValid)
+			}
+			var got authStatus
+			if err := json.Unmarshal([]byte(out), &got); err != nil {
+				t.Fatalf("output is not JSON: %v\n%s", err, out)
+			}
+			if tt.wantErr {
+				if got.Authenticated || got.Error == "" {
+					t.Errorf("JSON = %+v, want authenticated false with an error", got)
+				}
+				return
+			}
+			if got != tt.wantJSON {
+				t.Errorf("JSON = %+v, want %+v", got, tt.wantJSON)
+			}
+		})
+	}
+}
+
+func TestVerifyAuthWithoutKey(t *testing.T) {
+	app := newTestApp(&fakeAPI{})
+	app.cfg.CLI.APIKey = ""
+
+	var (
+		valid bool
+		err   error
+	)
+	captureStdout(t, func() { valid, err = app.VerifyAuth("table") })
+	if valid || err != nil {
+		t.Errorf("VerifyAuth() = %v, %v; want false, nil", valid, err)
+	}
+}
+
+// TestVerifyAuthErrorKinds checks that both output formats return errors main
+// can map to an exit code: a *url.Error when the API is unreachable and an
+// APIError when it answers with a failure
+func TestVerifyAuthErrorKinds(t *testing.T) {
+	failing := httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		w.WriteHeader(http.StatusInternalServerError)
+	}))
+	defer failing.Close()
+	closed := httptest.NewServer(http.NotFoundHandler())
+	closed.Close()
+
+	for _, format := range []string{"table", "json"} {
+		t.Run(format, func(t *testing.T) {
+			app := newTestApp(nil)
+			app.cfg.CLI.BaseURL = closed.URL
+			var err error
+			captureStdout(t, func() { _, err = app.VerifyAuth(format) })
+			var urlErr *url.Error
+			if !errors.As(err, &urlErr) {
+				t.Errorf("unreachable API: error = %v, want a *url.Error", err)
+			}
+
+			app = newTestApp(nil)
+			app.cfg.CLI.BaseURL = failing.URL
+			captureStdout(t, func() { _, err = app.VerifyAuth(format) })
+			var apiErr *client.APIError
+			if !errors.As(err, &apiErr) || errors.As(err, &urlErr) {
+				t.Errorf("500 response: error = %v, want an APIError only", err)
+			}
+		})
+	}
+}
+
+func TestSaveLinkTruncatesDescription(t *testing.T) {
+	api := &fakeAPI{}
+	app := newTestApp(api)
+
+	var err error
+	out := captureStdout(t, func() {
+		err = app.SaveLink("https://example.com", "one two three four", SaveOptions{DescriptionMaxLen: 10})
+	})
+	if err != nil {
+		t.Fatalf("SaveLink() error = %v", err)
+	}
+	if len(api.created) != 1 {
+		t.Fatalf("created %d links, want 1", len(api.created))
+	}
+	got := api.created[0]
+	if got.URL != "https://example.com" {
+		t.Errorf("URL = %q", got.URL)
+	}
+	if got.Description == nil || *got.Description != "one two…" {
+		t.Errorf("Description = %v, want %q", got.Description, "one two…")
+	}
+	if !strings.Contains(out, "Link saved successfully") {
+		t.Errorf("output = %q, want the success message", out)
+	}
+}
+
+func TestSaveLinkFromJSON(t *testing.T) {
+	tests := []struct {
+		name      string
+		input     string
+		wantErr   bool
+		wantURL   string
+		wantTitle string
+	}{
+		{
+			name:      "url and title",
+			input:     `{"url": "https://example.com/a", "title": "Example"}`,
+			wantURL:   "https://example.com/a",
+			wantTitle: "Example",
+		},
+		{
+			name:      "unknown fields are ignored",
+			input:     `{"url": "https://example.com/b", "title": "Tagged", "tags": ["go", "cli"]}`,
+			wantURL:   "https://example.com/b",
+			wantTitle: "Tagged",
+		},
+		{name: "missing url", input: `{"title": "No URL"}`, wantErr: true},
+		{name: "invalid url", input: `{"url": "https://example.com/%zz"}`, wantErr: true},
+		{name: "not an object", input: `["https://example.com"]`, wantErr: true},
+		{name: "malformed", input: `{"url": `, wantErr: true},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			api := &fakeAPI{}
+			app := newTestApp(api)
+
+			var err error
+			captureStdout(t, func() {
+				err = app.SaveLinkFromJSON(strings.NewReader(tt.input), SaveOptions{Interactivity: InteractiveAlways})
+			})
+			if (err != nil) != tt.wantErr {
+				t.Fatalf("SaveLinkFromJSON() error = %v, wantErr %v", err, tt.wantErr)
+			}
+			if tt.wantErr {
+				if len(api.created) != 0 {
+					t.Errorf("created %d links after an error", len(api.created))
+				}
+				return
+			}
+			if len(api.created) != 1 {
+				t.Fatalf("created %d links, want 1", len(api.created))
+			}
+			got := api.created[0]
+			if got.URL != tt.wantURL {
+				t.Errorf("URL = %q, want %q", got.URL, tt.wantURL)
+			}
+			if got.Title == nil || *got.Title != tt.wantTitle {
+				t.Errorf("Title = %v, want %q", got.Title, tt.wantTitle)
+			}
+		})
+	}
+}
+
+func TestSaveLinkReportsAPIError(t *testing.T) {
+	app := newTestApp(&failingCreateAPI{err: &client.APIError{StatusCode: http.StatusUnprocessableEntity, Message: "invalid URL"}})
+
+	var err error
+	captureStdout(t, func() { err = app.SaveLink("https://example.com", "", SaveOptions{}) })
+	if err == nil || !strings.Contains(err.Error(), "invalid URL") {
+		t.Errorf("SaveLink() error = %v, want the API message", err)
+	}
+}
+
+// failingCreateAPI is a fakeAPI whose CreateLink always fails
+type failingCreateAPI struct {
+	fakeAPI
+	err error
+}
+
+func (f *failingCreateAPI) CreateLink(models.LinkCreate) (*models.Link, error) {
+	return nil, f.err
+}
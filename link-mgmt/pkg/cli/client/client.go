@@ -5,16 +5,27 @@ import (
 	"encoding/json"
 	"fmt"
 	"io"
+	"math"
 	"net/http"
+	"os"
 	"strings"
 	"time"
+
+	"github.com/google/uuid"
+
+	"link-mgmt/pkg/utils"
 )
 
+// maxRetryWait is the longest Retry-After a rate-limited request waits out;
+// a longer wait fails with the RateLimitError instead
+const maxRetryWait = time.Minute
+
 // Client is an HTTP client for interacting with the link management API
 type Client struct {
 	baseURL    string
 	apiKey     string
 	httpClient *http.Client
+	maxRetries int // Retries after a 429 response; zero fails immediately
 }
 
 // NewClient creates a new API client
@@ -31,6 +42,12 @@ func NewClient(baseURL, apiKey string) *Client {
 	}
 }
 
+// SetMaxRetries sets how many times a request is retried after the API
+// responds with 429, waiting for the Retry-After duration between attempts
+func (c *Client) SetMaxRetries(n int) {
+	c.maxRetries = n
+}
+
 // buildRequest creates an HTTP request with proper headers
 func (c *Client) buildRequest(method, path string, body io.Reader) (*http.Request, error) {
 	url := fmt.Sprintf("%s%s", c.baseURL, path)
@@ -42,6 +59,8 @@ func (c *Client) buildRequest(method, path string, body io.Reader) (*http.Reques
 
 	// Set headers
 	req.Header.Set("Content-Type", "application/json")
+	// Tag each request so it can be matched against server logs
+	req.Header.Set("X-Request-ID", uuid.NewString())
 	// Only set Authorization header if API key is provided
 	if c.apiKey != "" {
 		req.Header.Set("Authorization", fmt.Sprintf("Bearer %s", c.apiKey))
@@ -50,34 +69,80 @@ func (c *Client) buildRequest(method, path string, body io.Reader) (*http.Reques
 	return req, nil
 }
 
+// send performs an HTTP request and reads the response body. A 429 response is
+// retried up to maxRetries times; the last 429 is returned if retries run out
+// or the server asks for a wait longer than maxRetryWait.
+func (c *Client) send(req *http.Request) (*http.Response, []byte, error) {
+	for attempt := 0; ; attempt++ {
+		resp, err := c.httpClient.Do(req)
+		if err != nil {
+			return nil, nil, fmt.Errorf("request failed: %w", err)
+		}
+
+		// Read response body
+		body, err := io.ReadAll(resp.Body)
+		resp.Body.Close()
+		if err != nil {
+			return resp, nil, fmt.Errorf("failed to read response: %w", err)
+		}
+
+		if resp.StatusCode != http.StatusTooManyRequests || attempt >= c.maxRetries {
+			return resp, body, nil
+		}
+
+		// Without a Retry-After hint, back off exponentially from one second
+		wait := parseRetryAfter(resp.Header.Get("Retry-After"), time.Now())
+		if wait <= 0 {
+			wait = time.Second << attempt
+		}
+		if wait > maxRetryWait {
+			return resp, body, nil
+		}
+		fmt.Fprintf(os.Stderr, "rate limited, retrying in %ds (attempt %d/%d)\n",
+			int(math.Ceil(wait.Seconds())), attempt+1, c.maxRetries)
+		time.Sleep(wait)
+
+		// The request body was consumed by the previous attempt
+		if req.GetBody != nil {
+			if req.Body, err = req.GetBody(); err != nil {
+				return nil, nil, fmt.Errorf("failed to rewind request body: %w", err)
+			}
+		}
+	}
+}
+
 // doRequest performs an HTTP request and handles the response
 func (c *Client) doRequest(req *http.Request, result interface{}) error {
-	resp, err := c.httpClient.Do(req)
+	resp, body, err := c.send(req)
 	if err != nil {
-		return fmt.Errorf("request failed: %w", err)
+		return err
 	}
-	defer resp.Body.Close()
 
-	// Read response body
-	body, err := io.ReadAll(resp.Body)
-	if err != nil {
-		return fmt.Errorf("failed to read response: %w", err)
+	if resp.StatusCode == http.StatusTooManyRequests {
+		return &RateLimitError{RetryAfter: parseRetryAfter(resp.Header.Get("Retry-After"), time.Now())}
 	}
 
 	// Check for HTTP errors
 	if resp.StatusCode < 200 || resp.StatusCode >= 300 {
+		apiErr := &APIError{
+			StatusCode: resp.StatusCode,
+			RequestID:  req.Header.Get("X-Request-ID"),
+		}
 		var errorResp struct {
 			Error string `json:"error"`
 		}
+		// The message ends up in logs and on the terminal, so strip escape
+		// sequences and line breaks from what the server sent
 		if err := json.Unmarshal(body, &errorResp); err == nil && errorResp.Error != "" {
-			return fmt.Errorf("API error (%d): %s", resp.StatusCode, errorResp.Error)
+			apiErr.Message = utils.SanitizeLine(errorResp.Error)
+			return apiErr
 		}
-		// If JSON parsing failed, return the raw body
-		errorMsg := string(body)
-		if errorMsg == "" {
-			errorMsg = resp.Status
+		// If JSON parsing failed, use the raw body
+		apiErr.Message = utils.SanitizeLine(string(body))
+		if apiErr.Message == "" {
+			apiErr.Message = resp.Status
 		}
-		return fmt.Errorf("API error (%d): %s", resp.StatusCode, errorMsg)
+		return apiErr
 	}
 
 	// Parse JSON response if result is provided
@@ -90,6 +155,26 @@ func (c *Client) doRequest(req *http.Request, result interface{}) error {
 	return nil
 }
 
+// DoRaw sends an authenticated request and returns the status code and raw body
+// without treating non-2xx statuses as errors. Rate-limited requests are retried
+// like any other request.
+func (c *Client) DoRaw(method, path string, body io.Reader) (int, []byte, error) {
+	req, err := c.buildRequest(method, path, body)
+	if err != nil {
+		return 0, nil, err
+	}
+
+	resp, respBody, err := c.send(req)
+	if err != nil {
+		if resp != nil {
+			return resp.StatusCode, nil, err
+		}
+		return 0, nil, err
+	}
+
+	return resp.StatusCode, respBody, nil
+}
+
 // doJSONRequest performs a JSON request (POST, PUT, PATCH)
 func (c *Client) doJSONRequest(method, path string, payload interface{}, result interface{}) error {
 	var body io.Reader
@@ -0,0 +1,452 @@
+package client
+
+import (
+	"encoding/json"
+	"errors"
+	"io"
+	"net/http"
+	"net/http/httptest"
+	"reflect"
+	"strings"
+	"sync"
+	"testing"
+	"time"
+
+	"github.com/google/uuid"
+
+	"link-mgmt/pkg/models"
+)
+
+const testAPIKey = "test-key"
+
+// recordedRequest is what the test server saw for one request
+type recordedRequest struct {
+	Method        string
+	Path          string
+	Authorization string
+	ContentType   string
+	RequestID     string
+	Body          string
+}
+
+// cannedResponse is what the test server answers with
+type cannedResponse struct {
+	Status  int
+	Body    string
+	Headers map[string]string
+}
+
+// testServer answers each request with the next canned response, repeating the
+// last one, and records every request it receives
+type testServer struct {
+	*httptest.Server
+
+	mu        sync.Mutex
+	responses []cannedResponse
+	requests  []recordedRequest
+}
+
+func newTestServer(t *testing.T, responses ...cannedResponse) *testServer {
+	t.Helper()
+	ts := &testServer{responses: responses}
+	ts.Server = httptest.NewServer(http.HandlerFunc(func(w http.ResponseWriter, r *http.Request) {
+		body, _ := io.ReadAll(r.Body)
+
+		ts.mu.Lock()
+		ts.requests = append(ts.requests, recordedRequest{
+			Method:        r.Method,
+			Path:          r.URL.Path,
+			Authorization: r.Header.Get("Authorization"),
+			ContentType:   r.Header.Get("Content-Type"),
+			RequestID:     r.Header.Get("X-Request-ID"),
+			Body:          string(body),
+		})
+		resp := ts.responses[0]
+		if len(ts.responses) > 1 {
+			ts.responses = ts.responses[1:]
+		}
+		ts.mu.Unlock()
+
+		for k, v := range resp.Headers {
+			w.Header().Set(k, v)
+		}
+		w.WriteHeader(resp.Status)
+		_, _ = io.WriteString(w, resp.Body)
+	}))
+	t.Cleanup(ts.Close)
+	return ts
+}
+
+// recorded returns the requests received so far
+func (ts *testServer) recorded() []recordedRequest {
+	ts.mu.Lock()
+	defer ts.mu.Unlock()
+	return append([]recordedRequest(nil), ts.requests...)
+}
+
+// assertJSONEqual compares two JSON documents ignoring formatting and key order
+func assertJSONEqual(t *testing.T, got, want string) {
+	t.Helper()
+	var g, w interface{}
+	if err := json.Unmarshal([]byte(got), &g); err != nil {
+		t.Fatalf("request body is not JSON: %v\n%s", err, got)
+	}
+	if err := json.Unmarshal([]byte(want), &w); err != nil {
+		t.Fatalf("bad expected JSON: %v", err)
+	}
+	if !reflect.DeepEqual(g, w) {
+		t.Errorf("request body = %s, want %s", got, want)
+	}
+}
+
+func TestClientRequests(t *testing.T) {
+	linkID := uuid.MustParse("4f1c2b6e-8d3a-4e5f-9a7b-1c2d3e4f5a6b")
+	title := "Example"
+	linkJSON := `{"id": "4f1c2b6e-8d3a-4e5f-9a7b-1c2d3e4f5a6b", "url": "https://example.com", "title": "Example"}`
+	userJSON := `{"id": "0b6a3c1d-2e4f-4a5b-8c7d-9e0f1a2b3c4d", "email": "me@example.com", "api_key": "new-key"}`
+
+	tests := []struct {
+		name       string
+		apiKey     string
+		status     int
+		response   string
+		call       func(c *Client) (interface{}, error)
+		wantMethod string
+		wantPath   string
+		wantBody   string // Empty for requests without a body
+		want       interface{}
+	}{
+		{
+			name:     "CreateLink",
+			apiKey:   testAPIKey,
+			status:   http.StatusCreated,
+			response: linkJSON,
+			call: func(c *Client) (interface{}, error) {
+				return c.CreateLink(models.LinkCreate{URL: "https://example.com", Title: &title})
+			},
+			wantMethod: http.MethodPost,
+			wantPath:   "/api/v1/links",
+			wantBody:   `{"url": "https://example.com", "title": "Example"}`,
+			want:       &models.Link{ID: linkID, URL: "https://example.com", Title: &title},
+		},
+		{
+			name:     "ListLinks",
+			apiKey:   testAPIKey,
+			status:   http.StatusOK,
+			response: "[" + linkJSON + "]",
+			call: func(c *Client) (interface{}, error) {
+				return c.ListLinks()
+			},
+			wantMethod: http.MethodGet,
+			wantPath:   "/api/v1/links",
+			want:       []models.Link{{ID: linkID, URL: "https://example.com", Title: &title}},
+		},
+		{
+			name:     "GetLink",
+			apiKey:   testAPIKey,
+			status:   http.StatusOK,
+			response: linkJSON,
+			call: func(c *Client) (interface{}, error) {
+				return c.GetLink(linkID)
+			},
+			wantMethod: http.MethodGet,
+			wantPath:   "/api/v1/links/" + linkID.String(),
+			want:       &models.Link{ID: linkID, URL: "https://example.com", Title: &title},
+		},
+		{
+			name:     "UpdateLink",
+			apiKey:   testAPIKey,
+			status:   http.StatusOK,
+			response: linkJSON,
+			call: func(c *Client) (interface{}, error) {
+				return c.UpdateLink(linkID, models.LinkUpdate{Title: &title})
+			},
+			wantMethod: http.MethodPut,
+			wantPath:   "/api/v1/links/" + linkID.String(),
+			wantBody:   `{"title": "Example"}`,
+			want:       &models.Link{ID: linkID, URL: "https://example.com", Title: &title},
+		},
+		{
+			name:     "CreateUser without API key",
+			status:   http.StatusCreated,
+			response: userJSON,
+			call: func(c *Client) (interface{}, error) {
+				return c.CreateUser("me@example.com")
+			},
+			wantMethod: http.MethodPost,
+			wantPath:   "/api/v1/users",
+			wantBody:   `{"email": "me@example.com"}`,
+			want: &models.User{
+				ID:     uuid.MustParse("0b6a3c1d-2e4f-4a5b-8c7d-9e0f1a2b3c4d"),
+				Email:  "me@example.com",
+				APIKey: "new-key",
+			},
+		},
+		{
+			name:     "GetMe",
+			apiKey:   testAPIKey,
+			status:   http.StatusOK,
+			response: userJSON,
+			call: func(c *Client) (interface{}, error) {
+				return c.GetMe()
+			},
+			wantMethod: http.MethodGet,
+			wantPath:   "/api/v1/users/me",
+			want: &models.User{
+				ID:     uuid.MustParse("0b6a3c1d-2e4f-4a5b-8c7d-9e0f1a2b3c4d"),
+				Email:  "me@example.com",
+				APIKey: "new-key",
+			},
+		},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			server := newTestServer(t, cannedResponse{Status: tt.status, Body: tt.response})
+			c := NewClient(server.URL+"/", tt.apiKey)
+
+			got, err := tt.call(c)
+			if err != nil {
+				t.Fatalf("unexpected error: %v", err)
+			}
+			if !reflect.DeepEqual(got, tt.want) {
+				t.Errorf("result = %+v, want %+v", got, tt.want)
+			}
+
+			reqs := server.recorded()
+			if len(reqs) != 1 {
+				t.Fatalf("server received %d requests, want 1", len(reqs))
+			}
+			req := reqs[0]
+			if req.Method != tt.wantMethod || req.Path != tt.wantPath {
+				t.Errorf("request = %s %s, want %s %s", req.Method, req.Path, tt.wantMethod, tt.wantPath)
+			}
+
+			wantAuth := ""
+			if tt.apiKey != "" {
+				wantAuth = "Bearer " + tt.apiKey
+			}
+			if req.Authorization != wantAuth {
+				t.Errorf("Authorization = %q, want %q", req.Authorization, wantAuth)
+			}
+			if req.ContentType != "application/json" {
+				t.Errorf("Content-Type = %q, want application/json", req.ContentType)
+			}
+			if req.RequestID == "" {
+				t.Error("X-Request-ID header not set")
+			}
+
+			if tt.wantBody == "" {
+				if req.Body != "" {
+					t.Errorf("request body = %q, want none", req.Body)
+				}
+			} else {
+				assertJSONEqual(t, req.Body, tt.wantBody)
+			}
+		})
+	}
+}
+
+func TestClientErrors(t *testing.T) {
+	tests := []struct {
+		name        string
+		status      int
+		body        string
+		wantMessage string
+		wantIs      error // Sentinel the error must match, or nil for neither
+	}{
+		{
+			name:        "404 with JSON error",
+			status:      http.StatusNotFound,
+			body:        `{"error": "link not found"}`,
+			wantMessage: "link not found",
+			wantIs:      ErrNotFound,
+		},
+		{
+			name:        "401",
+			status:      http.StatusUnauthorized,
+			body:        `{"error": "invalid API key"}`,
+			wantMessage: "invalid API key",
+			wantIs:      ErrUnauthorized,
+		},
+		{
+			name:        "403",
+			status:      http.StatusForbidden,
+			body:        `{"error": "forbidden"}`,
+			wantMessage: "forbidden",
+			wantIs:      ErrUnauthorized,
+		},
+		{
+			name:        "422 with JSON error",
+			status:      http.StatusUnprocessableEntity,
+			body:        `{"error": "url is required"}`,
+			wantMessage: "url is required",
+		},
+		{
+			name:        "500 with a plain text body",
+			status:      http.StatusInternalServerError,
+			body:        "upstream exploded",
+			wantMessage: "upstream exploded",
+		},
+		{
+			name:        "500 with escape sequences and newlines",
+			status:      http.StatusInternalServerError,
+			body:        "line one\n\x1b[31mline two\x1b[0m\x1b]0;pwned\x07",
+			wantMessage: "line one line two",
+		},
+		{
+			name:        "422 with escape sequences in the JSON error",
+			status:      http.StatusUnprocessableEntity,
+			body:        `{"error": "bad\u001b[2J\nrequest"}`,
+			wantMessage: "bad request",
+		},
+		{
+			name:        "500 with an empty body",
+			status:      http.StatusInternalServerError,
+			wantMessage: "500 Internal Server Error",
+		},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			server := newTestServer(t, cannedResponse{Status: tt.status, Body: tt.body})
+			c := NewClient(server.URL, testAPIKey)
+
+			_, err := c.ListLinks()
+			var apiErr *APIError
+			if !errors.As(err, &apiErr) {
+				t.Fatalf("error = %v (%T), want *APIError", err, err)
+			}
+			if apiErr.StatusCode != tt.status {
+				t.Errorf("StatusCode = %d, want %d", apiErr.StatusCode, tt.status)
+			}
+			if apiErr.Message != tt.wantMessage {
+				t.Errorf("Message = %q, want %q", apiErr.Message, tt.wantMessage)
+			}
+			if reqs := server.recorded(); apiErr.RequestID != reqs[0].RequestID {
+				t.Errorf("RequestID = %q, want the X-Request-ID sent (%q)", apiErr.RequestID, reqs[0].RequestID)
+			}
+
+			for _, sentinel := range []error{ErrNotFound, ErrUnauthorized} {
+				if got, want := errors.Is(err, sentinel), sentinel == tt.wantIs; got != want {
+					t.Errorf("errors.Is(err, %v) = %v, want %v", sentinel, got, want)
+				}
+			}
+		})
+	}
+}
+
+func TestClientErrorsThroughWrappedMethods(t *testing.T) {
+	server := newTestServer(t, cannedResponse{Status: http.StatusUnauthorized, Body: `{"error": "invalid API key"}`})
+	c := NewClient(server.URL, testAPIKey)
+
+	// GetMe wraps the API error; callers still match on the sentinel
+	_, err := c.GetMe()
+	if !errors.Is(err, ErrUnauthorized) {
+		t.Errorf("GetMe() error = %v, want ErrUnauthorized", err)
+	}
+}
+
+func TestClientRateLimit(t *testing.T) {
+	server := newTestServer(t, cannedResponse{
+		Status:  http.StatusTooManyRequests,
+		Body:    `{"error": "rate limit exceeded"}`,
+		Headers: map[string]string{"Retry-After": "7"},
+	})
+	c := NewClient(server.URL, testAPIKey)
+
+	_, err := c.ListLinks()
+	var rateErr *RateLimitError
+	if !errors.As(err, &rateErr) {
+		t.Fatalf("error = %v (%T), want *RateLimitError", err, err)
+	}
+	if rateErr.RetryAfter != 7*time.Second {
+		t.Errorf("RetryAfter = %v, want 7s", rateErr.RetryAfter)
+	}
+	if errors.Is(err, ErrNotFound) || errors.Is(err, ErrUnauthorized) {
+		t.Errorf("rate limit error matched a status sentinel: %v", err)
+	}
+	if n := len(server.recorded()); n != 1 {
+		t.Errorf("server received %d requests without retries, want 1", n)
+	}
+}
+
+func TestClientRetriesRateLimit(t *testing.T) {
+	server := newTestServer(t,
+		cannedResponse{Status: http.StatusTooManyRequests, Headers: map[string]string{"Retry-After": "1"}},
+		cannedResponse{Status: http.StatusCreated, Body: `{"url": "https://example.com"}`},
+	)
+	c := NewClient(server.URL, testAPIKey)
+	c.SetMaxRetries(2)
+
+	created, err := c.CreateLink(models.LinkCreate{URL: "https://example.com"})
+	if err != nil {
+		t.Fatalf("CreateLink() error = %v", err)
+	}
+	if created.URL != "https://example.com" {
+		t.Errorf("URL = %q", created.URL)
+	}
+
+	reqs := server.recorded()
+	if len(reqs) != 2 {
+		t.Fatalf("server received %d requests, want 2", len(reqs))
+	}
+	// The body is sent again on the retry
+	assertJSONEqual(t, reqs[1].Body, `{"url": "https://example.com"}`)
+}
+
+func TestClientRateLimitWaitTooLong(t *testing.T) {
+	server := newTestServer(t, cannedResponse{
+		Status:  http.StatusTooManyRequests,
+		Headers: map[string]string{"Retry-After": "3600"},
+	})
+	c := NewClient(server.URL, testAPIKey)
+	c.SetMaxRetries(3)
+
+	start := time.Now()
+	_, err := c.ListLinks()
+	var rateErr *RateLimitError
+	if !errors.As(err, &rateErr) {
+		t.Fatalf("error = %v (%T), want *RateLimitError", err, err)
+	}
+	if rateErr.RetryAfter != time.Hour {
+		t.Errorf("RetryAfter = %v, want 1h", rateErr.RetryAfter)
+	}
+	if n := len(server.recorded()); n != 1 {
+		t.Errorf("server received %d requests, want 1", n)
+	}
+	if elapsed := time.Since(start); elapsed > 5*time.Second {
+		t.Errorf("waited %v for a Retry-After beyond the cap", elapsed)
+	}
+}
+
+func TestDoRaw(t *testing.T) {
+	server := newTestServer(t,
+		cannedResponse{Status: http.StatusTooManyRequests, Headers: map[string]string{"Retry-After": "1"}},
+		cannedResponse{Status: http.StatusNotFound, Body: `{"error": "link not found"}`},
+	)
+	c := NewClient(server.URL, testAPIKey)
+	c.SetMaxRetries(1)
+
+	status, body, err := c.DoRaw(http.MethodPost, "/api/v1/links/search", strings.NewReader(`{"q": "go"}`))
+	if err != nil {
+		t.Fatalf("DoRaw() error = %v", err)
+	}
+	if status != http.StatusNotFound {
+		t.Errorf("status = %d, want 404 after retrying the 429", status)
+	}
+	if string(body) != `{"error": "link not found"}` {
+		t.Errorf("body = %q", body)
+	}
+
+	reqs := server.recorded()
+	if len(reqs) != 2 {
+		t.Fatalf("server received %d requests, want 2", len(reqs))
+	}
+	for _, req := range reqs {
+		if req.Authorization != "Bearer "+testAPIKey {
+			t.Errorf("Authorization = %q", req.Authorization)
+		}
+		assertJSONEqual(t, req.Body, `{"q": "go"}`)
+	}
+}
@@ -0,0 +1,80 @@
+package client
+
+import (
+	"errors"
+	"fmt"
+	"math"
+	"net/http"
+	"strconv"
+	"strings"
+	"time"
+)
+
+// Sentinel errors matched by APIError via errors.Is
+var (
+	ErrNotFound     = errors.New("not found")
+	ErrUnauthorized = errors.New("unauthorized")
+)
+
+// APIError is returned when the API responds with a non-2xx status other than 429
+type APIError struct {
+	StatusCode int
+	Message    string // The "error" field of the response, or the raw body
+	RequestID  string // X-Request-ID sent with the request
+}
+
+// Error implements the error interface
+func (e *APIError) Error() string {
+	if e.RequestID == "" {
+		return fmt.Sprintf("API error (%d): %s", e.StatusCode, e.Message)
+	}
+	return fmt.Sprintf("API error (%d): %s (request ID: %s)", e.StatusCode, e.Message, e.RequestID)
+}
+
+// Is lets callers match on ErrNotFound and ErrUnauthorized instead of status codes
+func (e *APIError) Is(target error) bool {
+	switch target {
+	case ErrNotFound:
+		return e.StatusCode == http.StatusNotFound
+	case ErrUnauthorized:
+		return e.StatusCode == http.StatusUnauthorized || e.StatusCode == http.StatusForbidden
+	}
+	return false
+}
+
+// RateLimitError is returned when the API responds with 429 Too Many Requests
+type RateLimitError struct {
+	RetryAfter time.Duration // Zero if the server did not send a usable Retry-After header
+}
+
+// Error implements the error interface
+func (e *RateLimitError) Error() string {
+	if e.RetryAfter > 0 {
+		return fmt.Sprintf("Rate limit exceeded. Try again in %d seconds.", int(math.Ceil(e.RetryAfter.Seconds())))
+	}
+	return "Rate limit exceeded. Try again later."
+}
+
+// parseRetryAfter parses a Retry-After header, which is either a number of
+// seconds or an HTTP-date. Returns zero if the value is missing or invalid.
+func parseRetryAfter(value string, now time.Time) time.Duration {
+	value = strings.TrimSpace(value)
+	if value == "" {
+		return 0
+	}
+
+	if seconds, err := strconv.Atoi(value); err == nil {
+		if seconds < 0 {
+			return 0
+		}
+		return time.Duration(seconds) * time.Second
+	}
+
+	if date, err := http.ParseTime(value); err == nil {
+		if wait := date.Sub(now); wait > 0 {
+			return wait
+		}
+	}
+
+	return 0
+}
@@ -0,0 +1,54 @@
+package client
+
+import (
+	"net/http"
+	"testing"
+	"time"
+)
+
+func TestParseRetryAfter(t *testing.T) {
+	now := time.Date(2026, time.January, 2, 15, 4, 5, 0, time.UTC)
+
+	tests := []struct {
+		name  string
+		value string
+		want  time.Duration
+	}{
+		{name: "seconds", value: "120", want: 2 * time.Minute},
+		{name: "seconds with spaces", value: " 5 ", want: 5 * time.Second},
+		{name: "zero seconds", value: "0", want: 0},
+		{name: "negative seconds", value: "-3", want: 0},
+		{name: "http date", value: now.Add(90 * time.Second).Format(http.TimeFormat), want: 90 * time.Second},
+		{name: "past http date", value: now.Add(-time.Hour).Format(http.TimeFormat), want: 0},
+		{name: "garbage", value: "soon", want: 0},
+		{name: "empty", value: "", want: 0},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			if got := parseRetryAfter(tt.value, now); got != tt.want {
+				t.Errorf("parseRetryAfter(%q) = %v, want %v", tt.value, got, tt.want)
+			}
+		})
+	}
+}
+
+func TestRateLimitErrorMessage(t *testing.T) {
+	if got, want := (&RateLimitError{RetryAfter: 1500 * time.Millisecond}).Error(), "Rate limit exceeded. Try again in 2 seconds."; got != want {
+		t.Errorf("Error() = %q, want %q", got, want)
+	}
+	if got, want := (&RateLimitError{}).Error(), "Rate limit exceeded. Try again later."; got != want {
+		t.Errorf("Error() = %q, want %q", got, want)
+	}
+}
+
+func TestAPIErrorMessage(t *testing.T) {
+	err := &APIError{StatusCode: http.StatusNotFound, Message: "link not found", RequestID: "abc-123"}
+	if got, want := err.Error(), "API error (404): link not found (request ID: abc-123)"; got != want {
+		t.Errorf("Error() = %q, want %q", got, want)
+	}
+	err.RequestID = ""
+	if got, want := err.Error(), "API error (404): link not found"; got != want {
+		t.Errorf("Error() without request ID = %q, want %q", got, want)
+	}
+}
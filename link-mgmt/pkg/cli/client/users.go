@@ -21,3 +21,12 @@ func (c *Client) CreateUser(email string) (*models.User, error) {
 	}
 	return &user, nil
 }
+
+// GetMe returns the user that owns the client's API key
+func (c *Client) GetMe() (*models.User, error) {
+	var user models.User
+	if err := c.doGetRequest("/api/v1/users/me", &user); err != nil {
+		return nil, fmt.Errorf("failed to get current user: %w", err)
+	}
+	return &user, nil
+}
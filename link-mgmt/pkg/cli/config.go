@@ -1,7 +1,12 @@
 package cli
 
 import (
+	"bufio"
 	"fmt"
+	"os"
+	"os/exec"
+	"path/filepath"
+	"strconv"
 	"strings"
 
 	"link-mgmt/pkg/config"
@@ -9,6 +14,118 @@ import (
 	"github.com/pelletier/go-toml/v2"
 )
 
+// EditConfig opens the config file in $EDITOR and validates it on save.
+// It does not need a loaded config, so a file with a syntax error can still be fixed.
+func EditConfig() error {
+	configPath, err := config.ConfigPath()
+	if err != nil {
+		return err
+	}
+
+	// Create the file from the commented template if it doesn't exist yet
+	if _, err := os.Stat(configPath); os.IsNotExist(err) {
+		if err := os.MkdirAll(filepath.Dir(configPath), 0755); err != nil {
+			return fmt.Errorf("failed to create config directory: %w", err)
+		}
+		if err := os.WriteFile(configPath, []byte(config.Template()), 0644); err != nil {
+			return fmt.Errorf("failed to write config file: %w", err)
+		}
+	}
+
+	editor := os.Getenv("VISUAL")
+	if strings.TrimSpace(editor) == "" {
+		editor = os.Getenv("EDITOR")
+	}
+	// $EDITOR may carry arguments (e.g. "code --wait")
+	editorArgs := strings.Fields(editor)
+	if len(editorArgs) == 0 {
+		editor = "vi"
+		editorArgs = []string{editor}
+	}
+
+	reader := bufio.NewReader(os.Stdin)
+	for {
+		cmd := exec.Command(editorArgs[0], append(editorArgs[1:], configPath)...)
+		cmd.Stdin = os.Stdin
+		cmd.Stdout = os.Stdout
+		cmd.Stderr = os.Stderr
+		if err := cmd.Run(); err != nil {
+			return fmt.Errorf("failed to run editor %q: %w", editor, err)
+		}
+
+		data, err := os.ReadFile(configPath)
+		if err != nil {
+			return fmt.Errorf("failed to read config file: %w", err)
+		}
+
+		var cfg config.Config
+		parseErr := toml.Unmarshal(data, &cfg)
+		if parseErr == nil {
+			return nil
+		}
+
+		fmt.Fprintf(os.Stderr, symbol("✗ Config file is not valid TOML: %v\n"), parseErr)
+		fmt.Fprint(os.Stderr, "Re-open the editor? [Y/n] ")
+		answer, _ := reader.ReadString('\n')
+		answer = strings.ToLower(strings.TrimSpace(answer))
+		if answer == "n" || answer == "no" {
+			return fmt.Errorf("config file left with errors: %w", parseErr)
+		}
+	}
+}
+
+// ValidateConfig checks the config file and prints any issues found.
+// Like EditConfig it does not need a loaded config. Returns false if issues were found.
+func ValidateConfig() (bool, error) {
+	configPath, err := config.ConfigPath()
+	if err != nil {
+		return false, err
+	}
+
+	issues, err := config.Validate()
+	if err != nil {
+		return false, err
+	}
+
+	if len(issues) == 0 {
+		fmt.Printf(symbol("✓ Config file is valid: %s\n"), configPath)
+		return true, nil
+	}
+
+	fmt.Printf(symbol("✗ Found %d issue(s) in %s:\n"), len(issues), configPath)
+	for _, issue := range issues {
+		fmt.Printf("  - %s\n", issue)
+	}
+	return false, nil
+}
+
+// ResetConfig restores the config file to commented defaults after confirmation.
+// Like EditConfig it does not need a loaded config. Returns false if the user declined.
+func ResetConfig(keepBaseURL, yes bool) (bool, error) {
+	configPath, err := config.ConfigPath()
+	if err != nil {
+		return false, err
+	}
+
+	if !yes {
+		if !isTerminal(os.Stdin) {
+			return false, fmt.Errorf("refusing to reset %s without confirmation (use --yes)", configPath)
+		}
+		question := fmt.Sprintf("Reset %s to defaults? The API key is kept", configPath)
+		if keepBaseURL {
+			question += ", as is cli.base_url"
+		}
+		if !confirm(bufio.NewReader(os.Stdin), question+".", false) {
+			return false, nil
+		}
+	}
+
+	if err := config.Reset(keepBaseURL); err != nil {
+		return false, err
+	}
+	return true, nil
+}
+
 // ShowConfig displays the current configuration
 func (a *App) ShowConfig() {
 	data, err := toml.Marshal(a.cfg)
@@ -62,6 +179,7 @@ func (a *App) SetConfig(setStr string) error {
 		switch key {
 		case "base_url":
 			a.cfg.CLI.BaseURL = value
+			a.cfg.CLI.BaseURLSource = config.SourceFile
 		case "api_key":
 			a.cfg.CLI.APIKey = value
 		case "scrape_timeout":
@@ -70,6 +188,30 @@ func (a *App) SetConfig(setStr string) error {
 				return fmt.Errorf("invalid scrape_timeout value: %s", value)
 			}
 			a.cfg.CLI.ScrapeTimeout = timeout
+		case "title_max_len":
+			var maxLen int
+			if _, err := fmt.Sscanf(value, "%d", &maxLen); err != nil || maxLen < 0 {
+				return fmt.Errorf("invalid title_max_len value: %s", value)
+			}
+			a.cfg.CLI.TitleMaxLen = maxLen
+		case "description_max_len":
+			var maxLen int
+			if _, err := fmt.Sscanf(value, "%d", &maxLen); err != nil || maxLen < 0 {
+				return fmt.Errorf("invalid description_max_len value: %s", value)
+			}
+			a.cfg.CLI.DescriptionMaxLen = maxLen
+		case "retries":
+			var retries int
+			if _, err := fmt.Sscanf(value, "%d", &retries); err != nil || retries < 0 {
+				return fmt.Errorf("invalid retries value: %s", value)
+			}
+			a.cfg.CLI.Retries = retries
+		case "verify_on_save":
+			verify, err := strconv.ParseBool(value)
+			if err != nil {
+				return fmt.Errorf("invalid verify_on_save value: %s", value)
+			}
+			a.cfg.CLI.VerifyOnSave = verify
 		default:
 			return fmt.Errorf("unknown cli key: %s", key)
 		}
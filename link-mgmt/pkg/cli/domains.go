@@ -0,0 +1,69 @@
+package cli
+
+import (
+	"encoding/csv"
+	"fmt"
+	"os"
+	"strconv"
+	"text/tabwriter"
+
+	"link-mgmt/pkg/utils"
+)
+
+// ShowTopDomains prints the n most common domains across the user's links
+// format is "table" (default) or "csv"
+func (a *App) ShowTopDomains(n int, format string) error {
+	if format != "table" && format != "csv" {
+		return fmt.Errorf("unknown format %q (expected table or csv)", format)
+	}
+
+	apiClient, err := a.getAPI()
+	if err != nil {
+		return fmt.Errorf("failed to create API client: %w", err)
+	}
+
+	links, err := apiClient.ListLinks()
+	if err != nil {
+		return fmt.Errorf("failed to list links: %w", a.ExplainConnectionError(err))
+	}
+
+	domains := utils.DomainFrequency(links)
+	if len(domains) > n {
+		domains = domains[:n]
+	}
+
+	percent := func(count int) float64 {
+		return float64(count) * 100 / float64(len(links))
+	}
+
+	if format == "csv" {
+		w := csv.NewWriter(os.Stdout)
+		if err := w.Write([]string{"rank", "domain", "links", "percent"}); err != nil {
+			return err
+		}
+		for i, d := range domains {
+			record := []string{
+				strconv.Itoa(i + 1),
+				d.Domain,
+				strconv.Itoa(d.Count),
+				strconv.FormatFloat(percent(d.Count), 'f', 1, 64),
+			}
+			if err := w.Write(record); err != nil {
+				return err
+			}
+		}
+		w.Flush()
+		return w.Error()
+	}
+
+	if len(domains) == 0 {
+		fmt.Println("No links found.")
+		return nil
+	}
+
+	w := tabwriter.NewWriter(os.Stdout, 0, 0, 3, ' ', 0)
+	for i, d := range domains {
+		fmt.Fprintf(w, "#%d\t%s\t(%d links)\t%.1f%%\n", i+1, d.Domain, d.Count, percent(d.Count))
+	}
+	return w.Flush()
+}
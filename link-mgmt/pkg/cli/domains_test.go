@@ -0,0 +1,46 @@
+package cli
+
+import (
+	"strings"
+	"testing"
+	"time"
+
+	"link-mgmt/pkg/models"
+)
+
+func TestShowTopDomainsCSV(t *testing.T) {
+	now := time.Now()
+	api := &fakeAPI{links: []models.Link{
+		linkAt("https://www.example.com/a", now),
+		linkAt("https://example.com/b", now),
+		linkAt("https://go.dev/doc", now),
+		linkAt("https://example.com/c", now),
+	}}
+	app := newTestApp(api)
+
+	var err error
+	out := captureStdout(t, func() { err = app.ShowTopDomains(2, "csv") })
+	if err != nil {
+		t.Fatalf("ShowTopDomains() error = %v", err)
+	}
+
+	want := "rank,domain,links,percent\n" +
+		"1,example.com,3,75.0\n" +
+		"2,go.dev,1,25.0\n"
+	if out != want {
+		t.Errorf("output =\n%s\nwant\n%s", out, want)
+	}
+}
+
+func TestShowTopDomainsEmpty(t *testing.T) {
+	app := newTestApp(&fakeAPI{})
+
+	var err error
+	out := captureStdout(t, func() { err = app.ShowTopDomains(5, "table") })
+	if err != nil {
+		t.Fatalf("ShowTopDomains() error = %v", err)
+	}
+	if strings.TrimSpace(out) != "No links found." {
+		t.Errorf("output = %q, want the empty message", out)
+	}
+}
@@ -0,0 +1,115 @@
+package cli
+
+import (
+	"fmt"
+	"os"
+	"strconv"
+	"strings"
+	"unicode"
+
+	"link-mgmt/pkg/models"
+	"link-mgmt/pkg/utils"
+)
+
+// SaveExtractedLinks saves every link found in a Markdown or HTML file, using
+// the link text as the title. On a terminal the user can exclude links before
+// saving; with dryRun the links are only listed. --verify prompts are not
+// used here, but --if-reachable and the title/description limits apply.
+func (a *App) SaveExtractedLinks(path string, dryRun bool, opts SaveOptions) error {
+	data, err := os.ReadFile(path)
+	if err != nil {
+		return fmt.Errorf("failed to read %s: %w", path, err)
+	}
+
+	found := utils.ExtractLinks(string(data))
+	if len(found) == 0 {
+		fmt.Printf("No links found in %s\n", path)
+		return nil
+	}
+
+	fmt.Printf("Found %d link(s) in %s:\n\n", len(found), path)
+	for i, link := range found {
+		if link.Text != "" {
+			fmt.Printf("%3d. %s\n     %s\n", i+1, utils.SanitizeLine(link.Text), link.URL)
+		} else {
+			fmt.Printf("%3d. %s\n", i+1, link.URL)
+		}
+	}
+	fmt.Println()
+
+	if dryRun {
+		fmt.Printf("Dry run: %d link(s) found. Nothing was saved.\n", len(found))
+		return nil
+	}
+
+	// Fail on missing configuration before asking which links to exclude
+	apiClient, err := a.getAPI()
+	if err != nil {
+		return fmt.Errorf("failed to create API client: %w", err)
+	}
+
+	reader, release, err := promptInput(opts.Interactivity)
+	if err != nil {
+		return err
+	}
+	defer release()
+
+	selected := found
+	if reader != nil {
+		fmt.Print("Numbers to exclude (e.g. 2,5), or Enter to save all: ")
+		answer, _ := reader.ReadString('\n')
+		excluded, err := parseExclusions(answer, len(found))
+		if err != nil {
+			return err
+		}
+		selected = nil
+		for i, link := range found {
+			if !excluded[i+1] {
+				selected = append(selected, link)
+			}
+		}
+	}
+
+	var saved, skipped, failed int
+	for _, link := range selected {
+		if opts.IfReachable {
+			if result := checkURL(link.URL); decideVerifyAction(link.URL, result) == verifyConfirm {
+				fmt.Printf(symbol("⚠️  %s\n  skipped: %s\n"), link.URL, result.describe())
+				skipped++
+				continue
+			}
+		}
+
+		linkCreate := models.LinkCreate{URL: link.URL}
+		if title := utils.TruncateAtWordBoundary(utils.SanitizeLine(link.Text), opts.TitleMaxLen); title != "" {
+			linkCreate.Title = &title
+		}
+
+		if _, err := apiClient.CreateLink(linkCreate); err != nil {
+			fmt.Printf(symbol("✗ %s\n  %v\n"), link.URL, a.ExplainConnectionError(err))
+			failed++
+			continue
+		}
+		fmt.Printf(symbol("✓ %s\n"), link.URL)
+		saved++
+	}
+
+	fmt.Printf(symbol("\n✓ Saved %d link(s): %d excluded, %d unreachable, %d failed\n"), saved, len(found)-len(selected), skipped, failed)
+	if failed > 0 {
+		return fmt.Errorf("%d link(s) could not be saved", failed)
+	}
+	return nil
+}
+
+// parseExclusions parses a comma- or space-separated list of 1-based indexes
+func parseExclusions(answer string, total int) (map[int]bool, error) {
+	excluded := make(map[int]bool)
+	for _, field := range strings.FieldsFunc(answer, func(r rune) bool { return r == ',' || unicode.IsSpace(r) }) {
+		n, err := strconv.Atoi(field)
+		if err != nil || n < 1 || n > total {
+			return nil, fmt.Errorf("invalid link number %q (expected 1-%d)", field, total)
+		}
+		excluded[n] = true
+	}
+	return excluded, nil
+}
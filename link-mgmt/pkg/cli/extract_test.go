@@ -0,0 +1,30 @@
+package cli
+
+import (
+	"os"
+	"path/filepath"
+	"strings"
+	"testing"
+
+	"link-mgmt/pkg/config"
+)
+
+func TestSaveExtractedLinksChecksConfigFirst(t *testing.T) {
+	path := filepath.Join(t.TempDir(), "links.md")
+	if err := os.WriteFile(path, []byte("[Example](https://example.com)\n"), 0644); err != nil {
+		t.Fatal(err)
+	}
+
+	app := &App{cfg: config.DefaultConfig()}
+
+	var err error
+	out := captureStdout(t, func() {
+		err = app.SaveExtractedLinks(path, false, SaveOptions{Interactivity: InteractiveAlways})
+	})
+	if err == nil || !strings.Contains(err.Error(), "API key not configured") {
+		t.Errorf("SaveExtractedLinks() error = %v, want the missing API key", err)
+	}
+	if strings.Contains(out, "Numbers to exclude") {
+		t.Errorf("asked which links to exclude before checking the config:\n%s", out)
+	}
+}
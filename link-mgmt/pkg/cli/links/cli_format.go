@@ -7,8 +7,45 @@ import (
 	"text/tabwriter"
 
 	"link-mgmt/pkg/models"
+	"link-mgmt/pkg/utils"
 )
 
+// asciiMarkers stand in for the unicode symbols in CLI and TUI output on
+// consoles that cannot display them, such as legacy Windows code pages
+var asciiMarkers = strings.NewReplacer(
+	"✓", "OK",
+	"✗", "X",
+	"❌", "X",
+	"⚠️", "!",
+	"💡", "*",
+	"⏳", "...",
+	"•", "-",
+	"→", "->",
+	"─", "-",
+)
+
+// asciiOutput is set by SetASCII
+var asciiOutput bool
+
+// SetASCII switches Markers to ASCII replacements for the unicode symbols
+func SetASCII(enabled bool) {
+	asciiOutput = enabled
+}
+
+// ASCII reports whether ASCII-only output was requested
+func ASCII() bool {
+	return asciiOutput
+}
+
+// Markers returns s with its unicode symbols (✓, ✗, ⚠️, ─, ...) replaced by
+// ASCII ones when ASCII output is on, and unchanged otherwise
+func Markers(s string) string {
+	if !asciiOutput {
+		return s
+	}
+	return asciiMarkers.Replace(s)
+}
+
 // FormatTableOutput formats links as a polished table for CLI output
 func FormatTableOutput(links []models.Link) string {
 	if len(links) == 0 {
@@ -25,7 +62,7 @@ func FormatTableOutput(links []models.Link) string {
 	// Table
 	w := tabwriter.NewWriter(&b, 0, 0, 3, ' ', 0)
 	fmt.Fprintln(w, "ID\tURL\tTitle\tCreated")
-	fmt.Fprintln(w, strings.Repeat("─", 8)+"\t"+strings.Repeat("─", 50)+"\t"+strings.Repeat("─", 40)+"\t"+strings.Repeat("─", 16))
+	fmt.Fprintln(w, Markers(strings.Repeat("─", 8)+"\t"+strings.Repeat("─", 50)+"\t"+strings.Repeat("─", 40)+"\t"+strings.Repeat("─", 16)))
 
 	for _, link := range links {
 		title := GetTitle(link)
@@ -57,10 +94,10 @@ func FormatSuccessMessage(link *models.Link) string {
 	created := FormatDate(link.CreatedAt)
 
 	b.WriteString("\n")
-	b.WriteString("✓ Link created successfully!\n")
+	b.WriteString(Markers("✓ Link created successfully!\n"))
 	b.WriteString("\n")
 	b.WriteString(fmt.Sprintf("  ID:      %s\n", idShort))
-	b.WriteString(fmt.Sprintf("  URL:     %s\n", link.URL))
+	b.WriteString(fmt.Sprintf("  URL:     %s\n", utils.SanitizeLine(link.URL)))
 	b.WriteString(fmt.Sprintf("  Title:   %s\n", title))
 	b.WriteString(fmt.Sprintf("  Created: %s\n", created))
 	b.WriteString("\n")
@@ -70,7 +107,7 @@ func FormatSuccessMessage(link *models.Link) string {
 
 // FormatErrorMessage formats an error message consistently
 func FormatErrorMessage(err error) string {
-	return fmt.Sprintf("❌ Error: %v\n", err)
+	return fmt.Sprintf(Markers("❌ Error: %v\n"), err)
 }
 
 // renderHeader renders a styled header
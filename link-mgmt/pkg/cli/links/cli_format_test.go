@@ -0,0 +1,41 @@
+package links
+
+import (
+	"strings"
+	"testing"
+	"time"
+	"unicode"
+
+	"github.com/google/uuid"
+
+	"link-mgmt/pkg/models"
+)
+
+func TestASCIIOutput(t *testing.T) {
+	title := "Example"
+	link := models.Link{ID: uuid.New(), URL: "https://example.com", Title: &title, CreatedAt: time.Now()}
+
+	if out := FormatTableOutput([]models.Link{link}); !strings.Contains(out, "─") {
+		t.Errorf("default table has no box-drawing separator:\n%s", out)
+	}
+
+	SetASCII(true)
+	defer SetASCII(false)
+
+	outputs := []string{
+		FormatTableOutput([]models.Link{link}),
+		FormatSuccessMessage(&link),
+		Markers("✓ saved • ✗ failed → ⚠️  warning 💡 hint ⏳ wait"),
+	}
+	for _, out := range outputs {
+		for _, r := range out {
+			if r > unicode.MaxASCII {
+				t.Errorf("ASCII output contains %q:\n%s", r, out)
+				break
+			}
+		}
+	}
+	if got := FormatSuccessMessage(&link); !strings.Contains(got, "OK Link created successfully!") {
+		t.Errorf("FormatSuccessMessage() = %q, want an OK marker", got)
+	}
+}
@@ -4,20 +4,24 @@ import (
 	"time"
 
 	"link-mgmt/pkg/models"
+	"link-mgmt/pkg/utils"
 
 	"github.com/google/uuid"
 )
 
-// GetTitle returns the title of a link, or a default value if missing
+// GetTitle returns the title of a link, sanitized for display, or a default value if missing
 func GetTitle(link models.Link) string {
-	if link.Title != nil && *link.Title != "" {
-		return *link.Title
+	if link.Title != nil {
+		if title := utils.SanitizeLine(*link.Title); title != "" {
+			return title
+		}
 	}
 	return "(no title)"
 }
 
-// TruncateURL truncates a URL to the specified max length
+// TruncateURL sanitizes a URL for display and truncates it to the specified max length
 func TruncateURL(url string, maxLen int) string {
+	url = utils.SanitizeLine(url)
 	if len(url) <= maxLen {
 		return url
 	}
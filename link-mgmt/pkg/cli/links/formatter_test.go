@@ -0,0 +1,89 @@
+package links
+
+import (
+	"strings"
+	"testing"
+	"time"
+
+	"github.com/google/uuid"
+
+	"link-mgmt/pkg/models"
+)
+
+func TestGetTitle(t *testing.T) {
+	title := func(s string) *string { return &s }
+
+	tests := []struct {
+		name  string
+		title *string
+		want  string
+	}{
+		{name: "missing", want: "(no title)"},
+		{name: "empty", title: title(""), want: "(no title)"},
+		{name: "only escape sequences", title: title("\x1b[2J\x1b[H"), want: "(no title)"},
+		{name: "plain", title: title("Example Domain"), want: "Example Domain"},
+		{name: "ANSI and OSC stripped", title: title("\x1b]0;pwned\x07\x1b[31mRed\x1b[0m"), want: "Red"},
+		{name: "newline collapsed", title: title("Line one\nLine two"), want: "Line one Line two"},
+		{name: "bidi override removed", title: title("abc\u202edef"), want: "abcdef"},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			if got := GetTitle(models.Link{Title: tt.title}); got != tt.want {
+				t.Errorf("GetTitle() = %q, want %q", got, tt.want)
+			}
+		})
+	}
+}
+
+func TestTruncateURL(t *testing.T) {
+	tests := []struct {
+		name   string
+		url    string
+		maxLen int
+		want   string
+	}{
+		{name: "short", url: "https://example.com", maxLen: 50, want: "https://example.com"},
+		{name: "truncated", url: "https://example.com/a/very/long/path", maxLen: 20, want: "https://example.c..."},
+		{name: "escapes stripped before measuring", url: "https://example.com/\x1b[8mhidden", maxLen: 50, want: "https://example.com/hidden"},
+		{name: "newline collapsed", url: "https://example.com/\nfake-row", maxLen: 50, want: "https://example.com/ fake-row"},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			if got := TruncateURL(tt.url, tt.maxLen); got != tt.want {
+				t.Errorf("TruncateURL(%q, %d) = %q, want %q", tt.url, tt.maxLen, got, tt.want)
+			}
+		})
+	}
+}
+
+func TestFormatTableOutputKeepsOneRowPerLink(t *testing.T) {
+	title := "Injected\nfake-id   https://evil.example   Fake row\x1b[2J"
+	out := FormatTableOutput([]models.Link{{
+		ID:        uuid.New(),
+		URL:       "https://example.com",
+		Title:     &title,
+		CreatedAt: time.Date(2026, time.March, 1, 12, 0, 0, 0, time.UTC),
+	}})
+
+	if strings.Contains(out, "\x1b") {
+		t.Errorf("table contains an escape sequence:\n%q", out)
+	}
+
+	var rows []string
+	for _, line := range strings.Split(out, "\n") {
+		if strings.Contains(line, "2026-03-01 12:00") {
+			rows = append(rows, line)
+		}
+	}
+	if len(rows) != 1 {
+		t.Fatalf("got %d data rows, want 1:\n%s", len(rows), out)
+	}
+	if !strings.Contains(rows[0], "Injected fake-id") {
+		t.Errorf("row = %q, want the title on one line", rows[0])
+	}
+	if strings.Contains(out, "\nfake-id") {
+		t.Errorf("title newline started a new line:\n%s", out)
+	}
+}
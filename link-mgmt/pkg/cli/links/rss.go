@@ -0,0 +1,70 @@
+package links
+
+import (
+	"encoding/xml"
+	"fmt"
+	"time"
+
+	"link-mgmt/pkg/models"
+)
+
+// rssFeed is an RSS 2.0 document; encoding/xml escapes titles and descriptions
+type rssFeed struct {
+	XMLName xml.Name   `xml:"rss"`
+	Version string     `xml:"version,attr"`
+	Channel rssChannel `xml:"channel"`
+}
+
+type rssChannel struct {
+	Title         string    `xml:"title"`
+	Link          string    `xml:"link"`
+	Description   string    `xml:"description"`
+	LastBuildDate string    `xml:"lastBuildDate"`
+	Items         []rssItem `xml:"item"`
+}
+
+type rssItem struct {
+	Title       string  `xml:"title"`
+	Link        string  `xml:"link"`
+	Description string  `xml:"description,omitempty"`
+	PubDate     string  `xml:"pubDate"`
+	GUID        rssGUID `xml:"guid"`
+}
+
+type rssGUID struct {
+	Value       string `xml:",chardata"`
+	IsPermaLink bool   `xml:"isPermaLink,attr"`
+}
+
+// FormatRSS renders links as an RSS 2.0 feed, one item per link.
+// siteURL is used as the channel link.
+func FormatRSS(links []models.Link, siteURL string) (string, error) {
+	feed := rssFeed{
+		Version: "2.0",
+		Channel: rssChannel{
+			Title:         "Saved links",
+			Link:          siteURL,
+			Description:   "Links saved with link-mgmt",
+			LastBuildDate: time.Now().Format(time.RFC1123Z),
+		},
+	}
+
+	for _, link := range links {
+		item := rssItem{
+			Title:   GetTitle(link),
+			Link:    link.URL,
+			PubDate: link.CreatedAt.Format(time.RFC1123Z),
+			GUID:    rssGUID{Value: link.ID.String()},
+		}
+		if link.Description != nil {
+			item.Description = *link.Description
+		}
+		feed.Channel.Items = append(feed.Channel.Items, item)
+	}
+
+	data, err := xml.MarshalIndent(feed, "", "  ")
+	if err != nil {
+		return "", fmt.Errorf("failed to render feed: %w", err)
+	}
+	return xml.Header + string(data) + "\n", nil
+}
@@ -0,0 +1,70 @@
+package cli
+
+import (
+	"bufio"
+	"fmt"
+	"os"
+	"strings"
+)
+
+// Interactivity controls whether commands may prompt the user
+type Interactivity int
+
+const (
+	InteractiveAuto   Interactivity = iota // Prompt only when stdin is a terminal
+	InteractiveNever                       // Never prompt, even on a terminal
+	InteractiveAlways                      // Prompt even when stdin is piped, reading answers from /dev/tty
+)
+
+// promptInput returns the reader prompt answers come from, or nil if prompts
+// must not be shown. The returned function releases the reader.
+func promptInput(mode Interactivity) (*bufio.Reader, func(), error) {
+	switch {
+	case mode == InteractiveNever:
+		return nil, func() {}, nil
+	case isTerminal(os.Stdin):
+		return bufio.NewReader(os.Stdin), func() {}, nil
+	case mode == InteractiveAlways:
+		tty, err := os.Open("/dev/tty")
+		if err != nil {
+			return nil, nil, fmt.Errorf("cannot prompt without a terminal: %w", err)
+		}
+		return bufio.NewReader(tty), func() { tty.Close() }, nil
+	default:
+		return nil, func() {}, nil
+	}
+}
+
+// confirm asks a yes/no question on stdout and reads the answer from reader.
+// An empty answer returns defaultYes.
+func confirm(reader *bufio.Reader, question string, defaultYes bool) bool {
+	hint := "[y/N]"
+	if defaultYes {
+		hint = "[Y/n]"
+	}
+	fmt.Printf("%s %s ", question, hint)
+
+	answer, err := reader.ReadString('\n')
+	answer = strings.ToLower(strings.TrimSpace(answer))
+	if err != nil && answer == "" {
+		return false
+	}
+
+	switch answer {
+	case "":
+		return defaultYes
+	case "y", "yes":
+		return true
+	default:
+		return false
+	}
+}
+
+// isTerminal reports whether f is attached to a terminal rather than a pipe or file
+func isTerminal(f *os.File) bool {
+	info, err := f.Stat()
+	if err != nil {
+		return false
+	}
+	return info.Mode()&os.ModeCharDevice != 0
+}
@@ -0,0 +1,59 @@
+package cli
+
+import (
+	"encoding/json"
+	"fmt"
+	"os"
+	"sort"
+
+	"link-mgmt/pkg/cli/links"
+)
+
+// ShowRecentLinks prints the n most recently saved links
+// format is "table" (default), "json" or "rss"
+func (a *App) ShowRecentLinks(n int, format string) error {
+	if format != "table" && format != "json" && format != "rss" {
+		return fmt.Errorf("unknown format %q (expected table, json or rss)", format)
+	}
+
+	apiClient, err := a.getAPI()
+	if err != nil {
+		return fmt.Errorf("failed to create API client: %w", err)
+	}
+
+	all, err := apiClient.ListLinks()
+	if err != nil {
+		return fmt.Errorf("failed to list links: %w", a.ExplainConnectionError(err))
+	}
+
+	// The API already returns newest first, but don't depend on it
+	sort.SliceStable(all, func(i, j int) bool {
+		return all[i].CreatedAt.After(all[j].CreatedAt)
+	})
+	if len(all) > n {
+		all = all[:n]
+	}
+
+	if format == "json" {
+		enc := json.NewEncoder(os.Stdout)
+		enc.SetIndent("", "  ")
+		return enc.Encode(all)
+	}
+
+	if format == "rss" {
+		feed, err := links.FormatRSS(all, a.cfg.CLI.BaseURL)
+		if err != nil {
+			return err
+		}
+		links.WriteToStdout(feed)
+		return nil
+	}
+
+	if len(all) == 0 {
+		fmt.Println("No links found.")
+		return nil
+	}
+
+	links.WriteToStdout(links.FormatTableOutput(all))
+	return nil
+}
@@ -0,0 +1,234 @@
+package cli
+
+import (
+	"bufio"
+	"errors"
+	"fmt"
+	"net/url"
+	"strings"
+	"sync"
+	"time"
+
+	"link-mgmt/pkg/models"
+	"link-mgmt/pkg/scraper"
+	"link-mgmt/pkg/utils"
+
+	"github.com/google/uuid"
+)
+
+const (
+	// titleFetchConcurrency bounds how many hosts are fetched from at once
+	titleFetchConcurrency = 4
+	// titleFetchHostDelay is the pause between two fetches to the same host
+	titleFetchHostDelay = time.Second
+)
+
+// titleProposal is the outcome of fetching the live title for a link
+type titleProposal struct {
+	link  models.Link
+	title string
+	err   error
+}
+
+// FillMissingTitles fetches page titles for links that have none and updates them.
+// Titles are truncated to titleMaxLen characters (0 means no limit). With
+// dryRun the proposed titles are only printed; with confirmEach every update
+// is confirmed interactively, reading answers as allowed by mode.
+func (a *App) FillMissingTitles(dryRun, confirmEach bool, mode Interactivity, titleMaxLen int) error {
+	var reader *bufio.Reader
+	if confirmEach && !dryRun {
+		var release func()
+		var err error
+		reader, release, err = promptInput(mode)
+		if err != nil {
+			return err
+		}
+		defer release()
+		if reader == nil {
+			return errors.New("--confirm-each needs a terminal to ask on; pass --interactive to read answers from /dev/tty")
+		}
+	}
+
+	apiClient, err := a.getAPI()
+	if err != nil {
+		return fmt.Errorf("failed to create API client: %w", err)
+	}
+
+	links, err := apiClient.ListLinks()
+	if err != nil {
+		return fmt.Errorf("failed to list links: %w", a.ExplainConnectionError(err))
+	}
+
+	var missing []models.Link
+	for _, link := range links {
+		if link.Title == nil || strings.TrimSpace(*link.Title) == "" {
+			missing = append(missing, link)
+		}
+	}
+	if len(missing) == 0 {
+		fmt.Println(symbol("✓ All links already have titles"))
+		return nil
+	}
+
+	scraperService := scraper.NewScraperService(a.cfg.CLI.BaseURL)
+	if err := scraperService.CheckHealth(); err != nil {
+		return fmt.Errorf("scraper service unavailable: %w", a.ExplainConnectionError(err))
+	}
+
+	fmt.Printf(symbol("⏳ Fetching titles for %d link(s)...\n\n"), len(missing))
+	proposals := a.fetchTitles(scraperService, missing, titleMaxLen)
+
+	var updated, skipped int
+	for _, p := range proposals {
+		if p.err != nil {
+			fmt.Printf(symbol("✗ %s\n  skipped: %v\n"), utils.SanitizeLine(p.link.URL), p.err)
+			skipped++
+			continue
+		}
+
+		fmt.Printf(symbol("• %s\n  → %s\n"), utils.SanitizeLine(p.link.URL), p.title)
+		if dryRun {
+			continue
+		}
+		if confirmEach && !confirm(reader, "  Apply this title?", true) {
+			skipped++
+			continue
+		}
+
+		title := p.title
+		if _, err := apiClient.UpdateLink(p.link.ID, models.LinkUpdate{Title: &title}); err != nil {
+			fmt.Printf(symbol("  ✗ update failed: %v\n"), a.ExplainConnectionError(err))
+			skipped++
+			continue
+		}
+		updated++
+	}
+
+	fmt.Println()
+	if dryRun {
+		fmt.Printf("Dry run: %d title(s) found, %d skipped. No links were changed.\n", len(proposals)-skipped, skipped)
+		return nil
+	}
+	fmt.Printf(symbol("✓ Updated %d link(s), %d skipped\n"), updated, skipped)
+	return nil
+}
+
+// ResolveTitle fetches the live title for a single link, truncated to
+// titleMaxLen characters (0 means no limit), and updates the stored title if
+// it differs. With dryRun the change is only shown.
+func (a *App) ResolveTitle(id string, dryRun bool, titleMaxLen int) error {
+	linkID, err := uuid.Parse(strings.TrimSpace(id))
+	if err != nil {
+		return fmt.Errorf("invalid link ID %q: %w", id, err)
+	}
+
+	apiClient, err := a.getAPI()
+	if err != nil {
+		return fmt.Errorf("failed to create API client: %w", err)
+	}
+
+	link, err := apiClient.GetLink(linkID)
+	if err != nil {
+		return fmt.Errorf("failed to get link: %w", a.ExplainConnectionError(err))
+	}
+
+	scraperService := scraper.NewScraperService(a.cfg.CLI.BaseURL)
+	fmt.Printf(symbol("⏳ Fetching title for %s...\n"), utils.SanitizeLine(link.URL))
+	proposal := a.fetchTitle(scraperService, *link, titleMaxLen)
+	if proposal.err != nil {
+		return fmt.Errorf("failed to fetch title: %w", proposal.err)
+	}
+
+	current := ""
+	if link.Title != nil {
+		current = *link.Title
+	}
+	if current == proposal.title {
+		fmt.Println(symbol("✓ Title is already up to date"))
+		fmt.Printf("  Title: %s\n", utils.SanitizeLine(current))
+		return nil
+	}
+
+	if current == "" {
+		current = "(no title)"
+	}
+	fmt.Printf("  Before: %s\n", utils.SanitizeLine(current))
+	fmt.Printf("  After:  %s\n", proposal.title)
+
+	if dryRun {
+		fmt.Println("\nDry run: the link was not changed.")
+		return nil
+	}
+
+	title := proposal.title
+	if _, err := apiClient.UpdateLink(link.ID, models.LinkUpdate{Title: &title}); err != nil {
+		return fmt.Errorf("failed to update link: %w", a.ExplainConnectionError(err))
+	}
+	fmt.Println(symbol("✓ Title updated"))
+	return nil
+}
+
+// fetchTitles scrapes titles for links, at most one request at a time per host
+// with a delay between requests to the same host. Results keep the input order.
+func (a *App) fetchTitles(scraperService *scraper.ScraperService, links []models.Link, maxLen int) []titleProposal {
+	proposals := make([]titleProposal, len(links))
+
+	var hosts []string
+	byHost := make(map[string][]int)
+	for i, link := range links {
+		host := ""
+		if u, err := url.Parse(link.URL); err == nil {
+			host = strings.ToLower(u.Hostname())
+		}
+		if _, ok := byHost[host]; !ok {
+			hosts = append(hosts, host)
+		}
+		byHost[host] = append(byHost[host], i)
+	}
+
+	sem := make(chan struct{}, titleFetchConcurrency)
+	var wg sync.WaitGroup
+	for _, host := range hosts {
+		wg.Add(1)
+		go func(indexes []int) {
+			defer wg.Done()
+			sem <- struct{}{}
+			defer func() { <-sem }()
+
+			for n, i := range indexes {
+				if n > 0 {
+					time.Sleep(titleFetchHostDelay)
+				}
+				proposals[i] = a.fetchTitle(scraperService, links[i], maxLen)
+			}
+		}(byHost[host])
+	}
+	wg.Wait()
+
+	return proposals
+}
+
+// fetchTitle scrapes a single link and returns its live title, normalized and
+// truncated to maxLen characters
+func (a *App) fetchTitle(scraperService *scraper.ScraperService, link models.Link, maxLen int) titleProposal {
+	result, err := scraperService.Scrape(link.URL, a.cfg.CLI.ScrapeTimeout)
+	if err != nil {
+		var scraperErr *scraper.ScraperError
+		if errors.As(err, &scraperErr) {
+			err = errors.New(utils.SanitizeLine(scraperErr.UserMessage()))
+		}
+		return titleProposal{link: link, err: err}
+	}
+
+	title := normalizeTitle(result.Title, maxLen)
+	if title == "" {
+		return titleProposal{link: link, err: errors.New("page has no title")}
+	}
+	return titleProposal{link: link, title: title}
+}
+
+// normalizeTitle strips control characters, collapses whitespace and truncates
+// the title to maxLen characters at a word boundary
+func normalizeTitle(title string, maxLen int) string {
+	return utils.TruncateAtWordBoundary(utils.SanitizeLine(title), maxLen)
+}
@@ -349,7 +349,7 @@ func (m *addLinkForm) renderReview() string {
 	b.WriteString(renderTitle("Review & Edit Link"))
 
 	// URL (read-only)
-	b.WriteString(successStyle.Render("✓"))
+	b.WriteString(successStyle.Render(symbol("✓")))
 	b.WriteString(" ")
 	b.WriteString(fieldLabelStyle.Render("URL:"))
 	b.WriteString(" " + m.urlInput.Value() + "\n\n")
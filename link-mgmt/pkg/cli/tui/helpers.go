@@ -7,6 +7,7 @@ import (
 
 	"link-mgmt/pkg/models"
 	"link-mgmt/pkg/scraper"
+	"link-mgmt/pkg/utils"
 
 	"github.com/charmbracelet/lipgloss"
 )
@@ -64,7 +65,7 @@ func renderLinkList(links []models.Link, selected int, title string, subtitle st
 	for i, link := range links {
 		marker := " "
 		if i == selected {
-			marker = selectedMarkerStyle.Render("→")
+			marker = selectedMarkerStyle.Render(symbol("→"))
 		}
 
 		title := formatLinkTitle(link)
@@ -90,16 +91,19 @@ func renderLinkList(links []models.Link, selected int, title string, subtitle st
 	return b.String()
 }
 
-// formatLinkTitle returns the title of a link or a default value
+// formatLinkTitle returns the title of a link, sanitized for display, or a default value
 func formatLinkTitle(link models.Link) string {
-	if link.Title != nil && *link.Title != "" {
-		return *link.Title
+	if link.Title != nil {
+		if title := utils.SanitizeLine(*link.Title); title != "" {
+			return title
+		}
 	}
 	return "(no title)"
 }
 
-// truncateURL truncates a URL to the specified max length
+// truncateURL sanitizes a URL for display and truncates it to the specified max length
 func truncateURL(url string, maxLen int) string {
+	url = utils.SanitizeLine(url)
 	if len(url) <= maxLen {
 		return url
 	}
@@ -114,12 +118,7 @@ func renderLinkDetails(link *models.Link, includeUserID bool) string {
 
 	var b strings.Builder
 
-	title := ""
-	if link.Title != nil && *link.Title != "" {
-		title = *link.Title
-	} else {
-		title = "(no title)"
-	}
+	title := formatLinkTitle(*link)
 
 	b.WriteString(fieldLabelStyle.Render("ID:"))
 	b.WriteString(fmt.Sprintf(" %s\n", linkIDStyle.Render(link.ID.String()[:8]+"...")))
@@ -130,7 +129,7 @@ func renderLinkDetails(link *models.Link, includeUserID bool) string {
 	}
 
 	b.WriteString(fieldLabelStyle.Render("URL:"))
-	b.WriteString(fmt.Sprintf(" %s\n", link.URL))
+	b.WriteString(fmt.Sprintf(" %s\n", utils.SanitizeLine(link.URL)))
 
 	b.WriteString(fieldLabelStyle.Render("Title:"))
 	b.WriteString(fmt.Sprintf(" %s\n", title))
@@ -158,7 +157,7 @@ func renderLinkDetailsFull(link *models.Link, maxWidth int) string {
 	// Description
 	b.WriteString(fieldLabelStyle.Render("Description:"))
 	if link.Description != nil && *link.Description != "" {
-		desc := *link.Description
+		desc := utils.SanitizeForTerminal(*link.Description)
 		// Use maxWidth for wrapping, but leave some margin for field label
 		wrapWidth := maxWidth - 2
 		if wrapWidth < 40 {
@@ -172,7 +171,7 @@ func renderLinkDetailsFull(link *models.Link, maxWidth int) string {
 	// Text
 	b.WriteString(fieldLabelStyle.Render("Text:"))
 	if link.Text != nil && *link.Text != "" {
-		text := *link.Text
+		text := utils.SanitizeForTerminal(*link.Text)
 		wrapWidth := maxWidth - 2
 		if wrapWidth < 40 {
 			wrapWidth = 40 // Minimum
@@ -284,7 +283,7 @@ func userFacingError(err error) error {
 
 	var scraperErr *scraper.ScraperError
 	if errors.As(err, &scraperErr) {
-		return errors.New(scraperErr.UserMessage())
+		return errors.New(utils.SanitizeLine(scraperErr.UserMessage()))
 	}
 
 	return err
@@ -0,0 +1,39 @@
+package tui
+
+import (
+	"testing"
+
+	"link-mgmt/pkg/models"
+)
+
+func TestFormatLinkTitle(t *testing.T) {
+	title := func(s string) *string { return &s }
+
+	tests := []struct {
+		name  string
+		title *string
+		want  string
+	}{
+		{name: "missing", want: "(no title)"},
+		{name: "only escape sequences", title: title("\x1b]0;pwned\x1b\\"), want: "(no title)"},
+		{name: "plain", title: title("Example Domain"), want: "Example Domain"},
+		{name: "CSI stripped", title: title("\x1b[1;32mGreen\x1b[0m"), want: "Green"},
+		{name: "C1 control removed", title: title("a\u009bb"), want: "ab"},
+		{name: "newline collapsed", title: title("one\n\ntwo"), want: "one two"},
+		{name: "bidi override removed", title: title("gnp.\u202eexe"), want: "gnp.exe"},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			if got := formatLinkTitle(models.Link{Title: tt.title}); got != tt.want {
+				t.Errorf("formatLinkTitle() = %q, want %q", got, tt.want)
+			}
+		})
+	}
+}
+
+func TestTruncateURLSanitizes(t *testing.T) {
+	if got, want := truncateURL("https://example.com/\x1b[8m\npath", 60), "https://example.com/ path"; got != want {
+		t.Errorf("truncateURL() = %q, want %q", got, want)
+	}
+}
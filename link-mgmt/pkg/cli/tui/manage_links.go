@@ -440,7 +440,7 @@ func (m *manageLinksModel) renderDeleteConfirm() string {
 
 	var b strings.Builder
 	b.WriteString(renderTitle("Delete Link"))
-	b.WriteString(warningStyle.Render("⚠️  Confirm Deletion") + "\n\n")
+	b.WriteString(warningStyle.Render(symbol("⚠️") + "  Confirm Deletion") + "\n\n")
 
 	b.WriteString(boldStyle.Render("Are you sure you want to delete:") + "\n")
 	b.WriteString(fmt.Sprintf("  %s\n", linkTitleStyle.Render(title)))
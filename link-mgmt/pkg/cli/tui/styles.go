@@ -4,6 +4,8 @@ import (
 	"strings"
 
 	"github.com/charmbracelet/lipgloss"
+
+	"link-mgmt/pkg/cli/links"
 )
 
 // Define a consistent color palette
@@ -92,13 +94,26 @@ func renderTitle(title string) string {
 }
 
 func renderSuccess(msg string) string {
-	return successStyle.Render("✓ " + msg)
+	return successStyle.Render(symbol("✓ ") + msg)
 }
 
 func renderError(msg string) string {
-	return errorStyle.Render("❌ " + msg)
+	return errorStyle.Render(symbol("❌ ") + msg)
 }
 
 func renderDivider(length int) string {
-	return dividerStyle.Render(strings.Repeat("─", length))
+	return dividerStyle.Render(symbol(strings.Repeat("─", length)))
+}
+
+// symbol returns s, or its ASCII replacement when --ascii is set
+func symbol(s string) string {
+	return links.Markers(s)
+}
+
+// overlayBorder returns the border for overlays, drawn in ASCII when --ascii is set
+func overlayBorder() lipgloss.Border {
+	if links.ASCII() {
+		return lipgloss.ASCIIBorder()
+	}
+	return lipgloss.RoundedBorder()
 }
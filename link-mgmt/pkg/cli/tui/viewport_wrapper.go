@@ -453,7 +453,7 @@ func (w *ViewportWrapper) renderFooter() string {
 	}
 	shortcuts = append(shortcuts, "q quit")
 
-	return helpStyle.Render(strings.Join(shortcuts, " • "))
+	return helpStyle.Render(strings.Join(shortcuts, symbol(" • ")))
 }
 
 // isDelegatingToWrappedModel checks if the wrapped model is delegating to another wrapped model.
@@ -483,7 +483,7 @@ func (w *ViewportWrapper) renderHelpOverlay() string {
 	overlayStyle := lipgloss.NewStyle().
 		Width(w.width).
 		Height(w.height).
-		Border(lipgloss.RoundedBorder()).
+		Border(overlayBorder()).
 		BorderForeground(colorPrimary).
 		Padding(1, 2).
 		Background(lipgloss.Color("236")). // Dark background
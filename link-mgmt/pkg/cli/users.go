@@ -1,11 +1,15 @@
 package cli
 
 import (
+	"encoding/json"
+	"errors"
 	"fmt"
+	"os"
 	"strings"
 
 	"link-mgmt/pkg/cli/client"
 	"link-mgmt/pkg/config"
+	"link-mgmt/pkg/utils"
 )
 
 // RegisterUser creates a new user account and saves the API key
@@ -26,7 +30,7 @@ To run migrations:
   From project root (Docker):  make migrate`)
 		}
 		// Don't wrap the error again since it already contains "failed to register user"
-		return err
+		return a.ExplainConnectionError(err)
 	}
 
 	// Save API key to config
@@ -37,13 +41,88 @@ To run migrations:
 
 	// Update the client with the new API key
 	a.client = client.NewClient(a.cfg.CLI.BaseURL, user.APIKey)
+	a.client.SetMaxRetries(a.retries)
 
-	fmt.Println("✓ User registered successfully!")
-	fmt.Printf("  Email: %s\n", user.Email)
+	fmt.Println(symbol("✓ User registered successfully!"))
+	fmt.Printf("  Email: %s\n", utils.SanitizeLine(user.Email))
 	fmt.Printf("  User ID: %s\n", user.ID.String())
 	fmt.Printf("  API key saved to config automatically\n")
-	fmt.Println("\n⚠️  Save this API key securely (it won't be shown again):")
+	fmt.Println(symbol("\n⚠️  Save this API key securely (it won't be shown again):"))
 	fmt.Printf("  %s\n", user.APIKey)
 
 	return nil
 }
+
+// authStatus is the JSON form of the --auth-verify result
+type authStatus struct {
+	Authenticated bool   `json:"authenticated"`
+	Email         string `json:"email,omitempty"`
+	APIKeyPrefix  string `json:"api_key_prefix,omitempty"`
+	BaseURL       string `json:"base_url,omitempty"`
+	Error         string `json:"error,omitempty"` // Why the key could not be checked
+}
+
+// VerifyAuth checks the stored API key against the server without changing
+// the config. It returns false if the key is missing or rejected, and an
+// error when the server could not be reached or failed to answer.
+// format is "table" (default, human-readable) or "json".
+func (a *App) VerifyAuth(format string) (bool, error) {
+	if format != "table" && format != "json" {
+		return false, fmt.Errorf("unknown format %q (expected table or json)", format)
+	}
+
+	report := func(status authStatus, message string) error {
+		if format == "json" {
+			return json.NewEncoder(os.Stdout).Encode(status)
+		}
+		fmt.Println(message)
+		return nil
+	}
+
+	if a.cfg.CLI.APIKey == "" {
+		return false, report(authStatus{}, symbol("✗ No API key configured"))
+	}
+
+	// fail reports a key that could not be checked. In JSON mode scripts still
+	// get a result on stdout; the returned error goes to stderr as usual.
+	fail := func(err error) (bool, error) {
+		if format == "json" {
+			if encErr := json.NewEncoder(os.Stdout).Encode(authStatus{Error: err.Error()}); encErr != nil {
+				return false, encErr
+			}
+		}
+		return false, a.ExplainConnectionError(err)
+	}
+
+	apiClient, err := a.getAPI()
+	if err != nil {
+		return fail(fmt.Errorf("failed to create API client: %w", err))
+	}
+
+	user, err := apiClient.GetMe()
+	if err != nil {
+		if errors.Is(err, client.ErrUnauthorized) {
+			return false, report(authStatus{}, symbol("✗ API key invalid or expired"))
+		}
+		return fail(err)
+	}
+
+	status := authStatus{
+		Authenticated: true,
+		Email:         user.Email,
+		APIKeyPrefix:  maskAPIKey(a.cfg.CLI.APIKey),
+		BaseURL:       a.cfg.CLI.BaseURL,
+	}
+	if err := report(status, fmt.Sprintf(symbol("✓ API key valid (user: %s)"), utils.SanitizeLine(user.Email))); err != nil {
+		return false, err
+	}
+	return true, nil
+}
+
+// maskAPIKey keeps the first four characters of the key, enough to tell keys apart
+func maskAPIKey(key string) string {
+	if len(key) <= 4 {
+		return "****"
+	}
+	return key[:4] + "****"
+}
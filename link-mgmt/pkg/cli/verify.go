@@ -0,0 +1,119 @@
+package cli
+
+import (
+	"fmt"
+	"net/http"
+	"time"
+)
+
+// verifyTimeout keeps pre-save checks short; slow sites are reported as unreachable
+const verifyTimeout = 10 * time.Second
+
+// verifyResult is the outcome of requesting a URL before saving it
+type verifyResult struct {
+	StatusCode int
+	FinalURL   string // URL after following redirects
+	Err        error  // Set when the URL could not be reached at all
+}
+
+// describe returns a one-line explanation of a failed verification
+func (r verifyResult) describe() string {
+	if r.Err != nil {
+		return fmt.Sprintf("could not reach URL: %v", r.Err)
+	}
+	return fmt.Sprintf("URL returned HTTP %d %s", r.StatusCode, http.StatusText(r.StatusCode))
+}
+
+// verifyAction is the next step of the save flow after verification
+type verifyAction int
+
+const (
+	verifyProceed       verifyAction = iota // 2xx: save the URL as given
+	verifyOfferRedirect                     // Redirected: offer to save the final destination
+	verifyConfirm                           // 4xx/5xx or unreachable: warn and ask before saving
+)
+
+// decideVerifyAction maps a verification result to the save flow's next step
+func decideVerifyAction(url string, r verifyResult) verifyAction {
+	if r.Err != nil || r.StatusCode >= 400 {
+		return verifyConfirm
+	}
+	if r.FinalURL != "" && r.FinalURL != url {
+		return verifyOfferRedirect
+	}
+	return verifyProceed
+}
+
+// checkURL requests the URL with HEAD, falling back to GET for servers that
+// don't support HEAD, and records the status and final URL after redirects
+func checkURL(url string) verifyResult {
+	httpClient := &http.Client{Timeout: verifyTimeout}
+
+	var resp *http.Response
+	var err error
+	for _, method := range []string{http.MethodHead, http.MethodGet} {
+		var req *http.Request
+		req, err = http.NewRequest(method, url, nil)
+		if err != nil {
+			return verifyResult{Err: err}
+		}
+
+		resp, err = httpClient.Do(req)
+		if err != nil {
+			return verifyResult{Err: err}
+		}
+		resp.Body.Close()
+
+		if resp.StatusCode != http.StatusMethodNotAllowed && resp.StatusCode != http.StatusNotImplemented {
+			break
+		}
+	}
+
+	return verifyResult{
+		StatusCode: resp.StatusCode,
+		FinalURL:   resp.Request.URL.String(),
+	}
+}
+
+// verifyBeforeSave checks that url responds and returns the URL to save, which
+// may be the redirect destination if the user chooses it. With opts.Force,
+// failed checks are reported but do not stop the save.
+func (a *App) verifyBeforeSave(url string, opts SaveOptions) (string, error) {
+	reader, release, err := promptInput(opts.Interactivity)
+	if err != nil {
+		return "", err
+	}
+	defer release()
+	interactive := reader != nil
+
+	fmt.Print(symbol("⏳ Checking URL... "))
+	result := checkURL(url)
+
+	switch decideVerifyAction(url, result) {
+	case verifyProceed:
+		fmt.Println(symbol("✓"))
+		return url, nil
+
+	case verifyOfferRedirect:
+		fmt.Println("↪")
+		fmt.Printf("  %s\n  redirects to\n  %s\n", url, result.FinalURL)
+		if interactive && confirm(reader, "Save the final destination instead?", true) {
+			return result.FinalURL, nil
+		}
+		return url, nil
+
+	default:
+		fmt.Println(symbol("✗"))
+		fmt.Printf(symbol("  ⚠️  %s\n"), result.describe())
+		if opts.Force {
+			return url, nil
+		}
+		if !interactive {
+			return "", fmt.Errorf("URL failed verification and prompts are disabled (use --force to save anyway)")
+		}
+		if confirm(reader, "Save it anyway?", false) {
+			return url, nil
+		}
+		return "", fmt.Errorf("save cancelled")
+	}
+}
@@ -0,0 +1,108 @@
+package cli
+
+import (
+	"errors"
+	"net/http"
+	"net/http/httptest"
+	"testing"
+)
+
+func TestDecideVerifyAction(t *testing.T) {
+	const url = "https://example.com/page"
+
+	tests := []struct {
+		name   string
+		result verifyResult
+		want   verifyAction
+	}{
+		{
+			name:   "2xx same URL",
+			result: verifyResult{StatusCode: http.StatusOK, FinalURL: url},
+			want:   verifyProceed,
+		},
+		{
+			name:   "2xx without final URL",
+			result: verifyResult{StatusCode: http.StatusNoContent},
+			want:   verifyProceed,
+		},
+		{
+			name:   "redirect to a different URL",
+			result: verifyResult{StatusCode: http.StatusOK, FinalURL: "https://example.com/moved"},
+			want:   verifyOfferRedirect,
+		},
+		{
+			name:   "redirect ending in 404",
+			result: verifyResult{StatusCode: http.StatusNotFound, FinalURL: "https://example.com/moved"},
+			want:   verifyConfirm,
+		},
+		{
+			name:   "5xx",
+			result: verifyResult{StatusCode: http.StatusBadGateway, FinalURL: url},
+			want:   verifyConfirm,
+		},
+		{
+			name:   "transport error",
+			result: verifyResult{Err: errors.New("dial tcp: connection refused")},
+			want:   verifyConfirm,
+		},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			if got := decideVerifyAction(url, tt.result); got != tt.want {
+				t.Errorf("decideVerifyAction() = %v, want %v", got, tt.want)
+			}
+		})
+	}
+}
+
+func TestCheckURL(t *testing.T) {
+	mux := http.NewServeMux()
+	mux.HandleFunc("/ok", func(w http.ResponseWriter, r *http.Request) {})
+	mux.HandleFunc("/moved", func(w http.ResponseWriter, r *http.Request) {
+		http.Redirect(w, r, "/ok", http.StatusMovedPermanently)
+	})
+	mux.HandleFunc("/gone", func(w http.ResponseWriter, r *http.Request) {
+		http.Redirect(w, r, "/missing", http.StatusFound)
+	})
+	mux.HandleFunc("/missing", http.NotFound)
+	mux.HandleFunc("/get-only", func(w http.ResponseWriter, r *http.Request) {
+		if r.Method != http.MethodGet {
+			w.WriteHeader(http.StatusMethodNotAllowed)
+		}
+	})
+	server := httptest.NewServer(mux)
+	defer server.Close()
+
+	tests := []struct {
+		path      string
+		wantFinal string
+		want      verifyAction
+	}{
+		{path: "/ok", wantFinal: "/ok", want: verifyProceed},
+		{path: "/moved", wantFinal: "/ok", want: verifyOfferRedirect},
+		{path: "/gone", wantFinal: "/missing", want: verifyConfirm},
+		{path: "/get-only", wantFinal: "/get-only", want: verifyProceed},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.path, func(t *testing.T) {
+			url := server.URL + tt.path
+			result := checkURL(url)
+			if result.Err != nil {
+				t.Fatalf("checkURL() error = %v", result.Err)
+			}
+			if result.FinalURL != server.URL+tt.wantFinal {
+				t.Errorf("FinalURL = %q, want %q", result.FinalURL, server.URL+tt.wantFinal)
+			}
+			if got := decideVerifyAction(url, result); got != tt.want {
+				t.Errorf("decideVerifyAction() = %v, want %v", got, tt.want)
+			}
+		})
+	}
+
+	server.Close()
+	if result := checkURL(server.URL + "/ok"); decideVerifyAction(server.URL+"/ok", result) != verifyConfirm {
+		t.Errorf("closed server: got %+v, want a transport error", result)
+	}
+}
@@ -9,6 +9,26 @@ import (
 	"github.com/pelletier/go-toml/v2"
 )
 
+// Source records where a config value came from
+type Source int
+
+const (
+	SourceDefault Source = iota // Built-in default; not written to the config file
+	SourceFile                  // Set in the config file
+	SourceEnv                   // Taken from an environment variable when the file was created
+)
+
+func (s Source) String() string {
+	switch s {
+	case SourceFile:
+		return "config file"
+	case SourceEnv:
+		return "BASE_URL environment variable"
+	default:
+		return "built-in default"
+	}
+}
+
 type Config struct {
 	// Database
 	Database struct {
@@ -23,9 +43,14 @@ type Config struct {
 
 	// CLI
 	CLI struct {
-		BaseURL       string `toml:"base_url"` // Base URL for all services (via nginx)
-		APIKey        string `toml:"api_key"`
-		ScrapeTimeout int    `toml:"scrape_timeout"` // Timeout for scraping operations in seconds
+		BaseURL           string `toml:"base_url,omitempty"` // Base URL for all services (via nginx)
+		BaseURLSource     Source `toml:"-"`                  // Where BaseURL came from
+		APIKey            string `toml:"api_key"`
+		ScrapeTimeout     int    `toml:"scrape_timeout"`      // Timeout for scraping operations in seconds
+		TitleMaxLen       int    `toml:"title_max_len"`       // Maximum length of fetched and saved titles in characters; 0 means no limit
+		DescriptionMaxLen int    `toml:"description_max_len"` // Maximum length of saved descriptions in characters; 0 means no limit
+		Retries           int    `toml:"retries"`             // Retries after a rate-limited (429) response when --retry is set; 0 turns retries off
+		VerifyOnSave      bool   `toml:"verify_on_save"`      // Check that URLs respond before saving them
 	} `toml:"cli"`
 
 	// Scraper
@@ -44,10 +69,75 @@ func DefaultConfig() *Config {
 	cfg.CLI.BaseURL = "http://localhost" // nginx reverse proxy on port 80
 	cfg.CLI.APIKey = ""
 	cfg.CLI.ScrapeTimeout = 30               // 30 seconds default
+	cfg.CLI.TitleMaxLen = 200                // characters
+	cfg.CLI.Retries = 3                      // attempts after the first
 	cfg.Scraper.BaseURL = "http://localhost" // scraper service default
 	return cfg
 }
 
+// Template returns the default configuration as TOML with comments describing each key
+func Template() string {
+	return renderTemplate(DefaultConfig())
+}
+
+// renderTemplate formats cfg as commented TOML in the same layout as Template
+func renderTemplate(cfg *Config) string {
+	return fmt.Sprintf(`# link-mgmt configuration
+# Edit with --config-edit, or set single keys with --config-set section.key=value
+
+[database]
+# PostgreSQL connection string (DATABASE_URL overrides this)
+url = %q
+
+[api]
+# Address the API server listens on
+host = %q
+port = %d
+
+[cli]
+# Base URL for all services (nginx reverse proxy)
+%s
+# API key for the CLI (saved automatically by --register)
+api_key = %q
+# Timeout for scraping operations in seconds
+scrape_timeout = %d
+# Maximum length of fetched and saved titles in characters (0 means no limit)
+title_max_len = %d
+# Maximum length of saved descriptions in characters (0 means no limit)
+description_max_len = %d
+# Retries after a rate-limited (429) response when --retry is set (0 turns retries off)
+retries = %d
+# Check that URLs respond before saving them (override with --verify / --no-verify)
+verify_on_save = %t
+
+[scraper]
+# Base URL for the scraper service (SCRAPER_BASE_URL overrides this)
+base_url = %q
+`,
+		cfg.Database.URL,
+		cfg.API.Host,
+		cfg.API.Port,
+		baseURLLine(cfg),
+		cfg.CLI.APIKey,
+		cfg.CLI.ScrapeTimeout,
+		cfg.CLI.TitleMaxLen,
+		cfg.CLI.DescriptionMaxLen,
+		cfg.CLI.Retries,
+		cfg.CLI.VerifyOnSave,
+		cfg.Scraper.BaseURL,
+	)
+}
+
+// baseURLLine renders cli.base_url, commented out while it is still the built-in
+// default so that later changes to the default are picked up
+func baseURLLine(cfg *Config) string {
+	line := fmt.Sprintf("base_url = %q", cfg.CLI.BaseURL)
+	if cfg.CLI.BaseURLSource == SourceDefault {
+		return "# " + line
+	}
+	return line
+}
+
 // ConfigPath returns the path to the config file
 func ConfigPath() (string, error) {
 	homeDir, err := os.UserHomeDir()
@@ -92,6 +182,7 @@ func Load() (*Config, error) {
 		}
 		if baseURL := os.Getenv("BASE_URL"); baseURL != "" {
 			cfg.CLI.BaseURL = baseURL
+			cfg.CLI.BaseURLSource = SourceEnv
 		}
 		if scraperBaseURL := os.Getenv("SCRAPER_BASE_URL"); scraperBaseURL != "" {
 			cfg.Scraper.BaseURL = scraperBaseURL
@@ -128,8 +219,26 @@ func Load() (*Config, error) {
 	if cfg.CLI.ScrapeTimeout == 0 {
 		cfg.CLI.ScrapeTimeout = defaultCfg.CLI.ScrapeTimeout
 	}
+	// title_max_len = 0 turns truncation off and retries = 0 turns retries off,
+	// so only a missing key gets the default
+	var explicit struct {
+		CLI struct {
+			TitleMaxLen *int `toml:"title_max_len"`
+			Retries     *int `toml:"retries"`
+		} `toml:"cli"`
+	}
+	if err := toml.Unmarshal(data, &explicit); err == nil {
+		if explicit.CLI.TitleMaxLen == nil {
+			cfg.CLI.TitleMaxLen = defaultCfg.CLI.TitleMaxLen
+		}
+		if explicit.CLI.Retries == nil {
+			cfg.CLI.Retries = defaultCfg.CLI.Retries
+		}
+	}
 	if cfg.CLI.BaseURL == "" {
 		cfg.CLI.BaseURL = defaultCfg.CLI.BaseURL
+	} else {
+		cfg.CLI.BaseURLSource = SourceFile
 	}
 	if cfg.Scraper.BaseURL == "" {
 		cfg.Scraper.BaseURL = defaultCfg.Scraper.BaseURL
@@ -146,6 +255,42 @@ func Load() (*Config, error) {
 	return &cfg, nil
 }
 
+// Reset rewrites the config file with commented defaults. The API key is kept
+// since it cannot be recovered once lost, and cli.base_url is kept if keepBaseURL is set.
+func Reset(keepBaseURL bool) error {
+	configPath, err := ConfigPath()
+	if err != nil {
+		return err
+	}
+
+	cfg := DefaultConfig()
+
+	data, err := os.ReadFile(configPath)
+	if err != nil && !os.IsNotExist(err) {
+		return fmt.Errorf("failed to read config file: %w", err)
+	}
+	if err == nil {
+		var current Config
+		if err := toml.Unmarshal(data, &current); err != nil {
+			return fmt.Errorf("failed to parse config file, so the API key cannot be preserved (fix it with --config-edit): %w", err)
+		}
+		cfg.CLI.APIKey = current.CLI.APIKey
+		if keepBaseURL && current.CLI.BaseURL != "" {
+			cfg.CLI.BaseURL = current.CLI.BaseURL
+			cfg.CLI.BaseURLSource = SourceFile
+		}
+	}
+
+	if err := os.MkdirAll(filepath.Dir(configPath), 0755); err != nil {
+		return fmt.Errorf("failed to create config directory: %w", err)
+	}
+	if err := os.WriteFile(configPath, []byte(renderTemplate(cfg)), 0644); err != nil {
+		return fmt.Errorf("failed to write config file: %w", err)
+	}
+
+	return nil
+}
+
 // Save writes the configuration to the config file
 func Save(cfg *Config) error {
 	configPath, err := ConfigPath()
@@ -168,8 +313,12 @@ func Save(cfg *Config) error {
 		return fmt.Errorf("failed to create config directory: %w", err)
 	}
 
-	// Marshal to TOML
-	data, err := toml.Marshal(cfg)
+	// Marshal to TOML, leaving out a base URL that is still the built-in default
+	out := *cfg
+	if out.CLI.BaseURLSource == SourceDefault {
+		out.CLI.BaseURL = ""
+	}
+	data, err := toml.Marshal(&out)
 	if err != nil {
 		return fmt.Errorf("failed to marshal config: %w", err)
 	}
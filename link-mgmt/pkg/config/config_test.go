@@ -0,0 +1,234 @@
+package config
+
+import (
+	"os"
+	"path/filepath"
+	"testing"
+
+	"github.com/pelletier/go-toml/v2"
+)
+
+// useTempHome points ConfigPath at an empty home directory and clears the
+// environment overrides Load applies
+func useTempHome(t *testing.T) string {
+	t.Helper()
+	home := t.TempDir()
+	t.Setenv("HOME", home)
+	t.Setenv("BASE_URL", "")
+	t.Setenv("DATABASE_URL", "")
+	t.Setenv("SCRAPER_BASE_URL", "")
+	return home
+}
+
+func writeConfig(t *testing.T, home, contents string) {
+	t.Helper()
+	dir := filepath.Join(home, ".config", "link-mgmt")
+	if err := os.MkdirAll(dir, 0755); err != nil {
+		t.Fatal(err)
+	}
+	if err := os.WriteFile(filepath.Join(dir, "config.toml"), []byte(contents), 0644); err != nil {
+		t.Fatal(err)
+	}
+}
+
+func TestLoadBaseURLSource(t *testing.T) {
+	defaultURL := DefaultConfig().CLI.BaseURL
+
+	tests := []struct {
+		name       string
+		file       string // config file contents; empty means no file
+		env        string // BASE_URL
+		wantURL    string
+		wantSource Source
+	}{
+		{
+			name:       "no file uses the default",
+			wantURL:    defaultURL,
+			wantSource: SourceDefault,
+		},
+		{
+			name:       "file without base_url uses the default",
+			file:       "[cli]\napi_key = \"abc\"\n",
+			wantURL:    defaultURL,
+			wantSource: SourceDefault,
+		},
+		{
+			name:       "file with base_url",
+			file:       "[cli]\nbase_url = \"http://localhost\"\n",
+			wantURL:    "http://localhost",
+			wantSource: SourceFile,
+		},
+		{
+			name:       "BASE_URL on first creation",
+			env:        "http://links.example.com",
+			wantURL:    "http://links.example.com",
+			wantSource: SourceEnv,
+		},
+		{
+			name:       "BASE_URL ignored once the file exists",
+			file:       "[cli]\nbase_url = \"http://saved.example.com\"\n",
+			env:        "http://links.example.com",
+			wantURL:    "http://saved.example.com",
+			wantSource: SourceFile,
+		},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			home := useTempHome(t)
+			if tt.file != "" {
+				writeConfig(t, home, tt.file)
+			}
+			if tt.env != "" {
+				t.Setenv("BASE_URL", tt.env)
+			}
+
+			cfg, err := Load()
+			if err != nil {
+				t.Fatalf("Load() error = %v", err)
+			}
+			if cfg.CLI.BaseURL != tt.wantURL {
+				t.Errorf("BaseURL = %q, want %q", cfg.CLI.BaseURL, tt.wantURL)
+			}
+			if cfg.CLI.BaseURLSource != tt.wantSource {
+				t.Errorf("BaseURLSource = %v, want %v", cfg.CLI.BaseURLSource, tt.wantSource)
+			}
+		})
+	}
+}
+
+func TestLoadKeepsSourceAcrossSave(t *testing.T) {
+	useTempHome(t)
+
+	cfg, err := Load()
+	if err != nil {
+		t.Fatalf("Load() error = %v", err)
+	}
+	cfg.CLI.APIKey = "abc"
+	if err := Save(cfg); err != nil {
+		t.Fatalf("Save() error = %v", err)
+	}
+
+	path, err := ConfigPath()
+	if err != nil {
+		t.Fatal(err)
+	}
+	data, err := os.ReadFile(path)
+	if err != nil {
+		t.Fatal(err)
+	}
+	var raw struct {
+		CLI map[string]interface{} `toml:"cli"`
+	}
+	if err := toml.Unmarshal(data, &raw); err != nil {
+		t.Fatal(err)
+	}
+	if _, ok := raw.CLI["base_url"]; ok {
+		t.Errorf("default base URL was written to the config file:\n%s", data)
+	}
+
+	cfg, err = Load()
+	if err != nil {
+		t.Fatalf("Load() error = %v", err)
+	}
+	if cfg.CLI.BaseURLSource != SourceDefault {
+		t.Errorf("BaseURLSource after Save = %v, want %v", cfg.CLI.BaseURLSource, SourceDefault)
+	}
+
+	cfg.CLI.BaseURL = "http://localhost"
+	cfg.CLI.BaseURLSource = SourceFile
+	if err := Save(cfg); err != nil {
+		t.Fatalf("Save() error = %v", err)
+	}
+	cfg, err = Load()
+	if err != nil {
+		t.Fatalf("Load() error = %v", err)
+	}
+	if cfg.CLI.BaseURLSource != SourceFile {
+		t.Errorf("BaseURLSource after setting base_url = %v, want %v", cfg.CLI.BaseURLSource, SourceFile)
+	}
+}
+
+func TestResetKeepsExplicitBaseURL(t *testing.T) {
+	home := useTempHome(t)
+	writeConfig(t, home, "[cli]\nbase_url = \"http://localhost\"\napi_key = \"abc\"\n")
+
+	if err := Reset(true); err != nil {
+		t.Fatalf("Reset() error = %v", err)
+	}
+	cfg, err := Load()
+	if err != nil {
+		t.Fatalf("Load() error = %v", err)
+	}
+	if cfg.CLI.BaseURLSource != SourceFile {
+		t.Errorf("BaseURLSource = %v, want %v", cfg.CLI.BaseURLSource, SourceFile)
+	}
+
+	if err := Reset(false); err != nil {
+		t.Fatalf("Reset() error = %v", err)
+	}
+	cfg, err = Load()
+	if err != nil {
+		t.Fatalf("Load() error = %v", err)
+	}
+	if cfg.CLI.BaseURLSource != SourceDefault {
+		t.Errorf("BaseURLSource = %v, want %v", cfg.CLI.BaseURLSource, SourceDefault)
+	}
+	if cfg.CLI.APIKey != "abc" {
+		t.Errorf("APIKey = %q, want it kept", cfg.CLI.APIKey)
+	}
+}
+
+func TestLoadTitleMaxLen(t *testing.T) {
+	tests := []struct {
+		name string
+		file string
+		want int
+	}{
+		{name: "missing key uses the default", file: "[cli]\napi_key = \"abc\"\n", want: DefaultConfig().CLI.TitleMaxLen},
+		{name: "zero turns truncation off", file: "[cli]\ntitle_max_len = 0\n", want: 0},
+		{name: "explicit limit", file: "[cli]\ntitle_max_len = 80\n", want: 80},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			home := useTempHome(t)
+			writeConfig(t, home, tt.file)
+
+			cfg, err := Load()
+			if err != nil {
+				t.Fatalf("Load() error = %v", err)
+			}
+			if cfg.CLI.TitleMaxLen != tt.want {
+				t.Errorf("TitleMaxLen = %d, want %d", cfg.CLI.TitleMaxLen, tt.want)
+			}
+		})
+	}
+}
+
+func TestLoadRetries(t *testing.T) {
+	tests := []struct {
+		name string
+		file string
+		want int
+	}{
+		{name: "missing key uses the default", file: "[cli]\napi_key = \"abc\"\n", want: DefaultConfig().CLI.Retries},
+		{name: "zero turns retries off", file: "[cli]\nretries = 0\n", want: 0},
+		{name: "explicit count", file: "[cli]\nretries = 5\n", want: 5},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			home := useTempHome(t)
+			writeConfig(t, home, tt.file)
+
+			cfg, err := Load()
+			if err != nil {
+				t.Fatalf("Load() error = %v", err)
+			}
+			if cfg.CLI.Retries != tt.want {
+				t.Errorf("Retries = %d, want %d", cfg.CLI.Retries, tt.want)
+			}
+		})
+	}
+}
@@ -0,0 +1,126 @@
+package config
+
+import (
+	"fmt"
+	"os"
+	"sort"
+	"strings"
+
+	"github.com/pelletier/go-toml/v2"
+)
+
+// knownKeys lists every key the config file may contain and its expected TOML type
+var knownKeys = map[string]map[string]string{
+	"database": {
+		"url": "string",
+	},
+	"api": {
+		"host": "string",
+		"port": "integer",
+	},
+	"cli": {
+		"base_url":            "string",
+		"api_key":             "string",
+		"scrape_timeout":      "integer",
+		"title_max_len":       "integer",
+		"description_max_len": "integer",
+		"retries":             "integer",
+		"verify_on_save":      "boolean",
+	},
+	"scraper": {
+		"base_url": "string",
+	},
+}
+
+// recommendedKeys should be set for the CLI to reach the API. cli.base_url is not
+// listed since it falls back to the built-in default when left out.
+var recommendedKeys = []string{"cli.api_key"}
+
+// Validate checks the config file for TOML syntax errors, unknown keys, values of
+// the wrong type and missing recommended keys. It returns one message per issue;
+// the error is only set when the file cannot be read.
+func Validate() ([]string, error) {
+	configPath, err := ConfigPath()
+	if err != nil {
+		return nil, err
+	}
+
+	data, err := os.ReadFile(configPath)
+	if os.IsNotExist(err) {
+		return []string{fmt.Sprintf("config file %s does not exist", configPath)}, nil
+	}
+	if err != nil {
+		return nil, fmt.Errorf("failed to read config file: %w", err)
+	}
+
+	var raw map[string]interface{}
+	if err := toml.Unmarshal(data, &raw); err != nil {
+		return []string{fmt.Sprintf("invalid TOML: %v", err)}, nil
+	}
+
+	var issues []string
+	for _, section := range sortedKeys(raw) {
+		keys, ok := knownKeys[section]
+		if !ok {
+			issues = append(issues, fmt.Sprintf("unknown section [%s]", section))
+			continue
+		}
+
+		table, ok := raw[section].(map[string]interface{})
+		if !ok {
+			issues = append(issues, fmt.Sprintf("%s should be a table, got %s", section, tomlType(raw[section])))
+			continue
+		}
+
+		for _, key := range sortedKeys(table) {
+			want, ok := keys[key]
+			if !ok {
+				issues = append(issues, fmt.Sprintf("unknown key %s.%s", section, key))
+				continue
+			}
+			if got := tomlType(table[key]); got != want {
+				issues = append(issues, fmt.Sprintf("%s.%s should be a %s, got %s", section, key, want, got))
+			}
+		}
+	}
+
+	for _, path := range recommendedKeys {
+		parts := strings.SplitN(path, ".", 2)
+		table, _ := raw[parts[0]].(map[string]interface{})
+		if value, _ := table[parts[1]].(string); strings.TrimSpace(value) == "" {
+			issues = append(issues, fmt.Sprintf("recommended key %s is not set", path))
+		}
+	}
+
+	return issues, nil
+}
+
+// sortedKeys returns the keys of a decoded TOML table in a stable order
+func sortedKeys(m map[string]interface{}) []string {
+	keys := make([]string, 0, len(m))
+	for k := range m {
+		keys = append(keys, k)
+	}
+	sort.Strings(keys)
+	return keys
+}
+
+// tomlType names the TOML type of a decoded value
+func tomlType(v interface{}) string {
+	switch v.(type) {
+	case string:
+		return "string"
+	case int64:
+		return "integer"
+	case float64:
+		return "float"
+	case bool:
+		return "boolean"
+	case map[string]interface{}:
+		return "table"
+	case []interface{}:
+		return "array"
+	default:
+		return "datetime"
+	}
+}
@@ -12,6 +12,7 @@ type ScrapeResponse struct {
 	Success     bool   `json:"success"`
 	URL         string `json:"url"`
 	Title       string `json:"title,omitempty"`
+	Description string `json:"description,omitempty"` // From og:description or meta description
 	Text        string `json:"text,omitempty"`
 	ExtractedAt string `json:"extracted_at,omitempty"`
 	Error       string `json:"error,omitempty"`
@@ -83,10 +83,29 @@ func (s *LinkService) CreateLinkWithScraping(
 	}
 
 	// Step 3: Merge scraped content (only fill empty fields if OnlyFillEmpty is true)
+	update, changed := mergeScrapedContent(link, scrapeResult, scrapeOptions.OnlyFillEmpty)
+
+	// Step 4: Update link with enriched content
+	if changed {
+		updated, err := s.UpdateLink(ctx, link.ID, userID, update)
+		if err != nil {
+			// Log error but return original link
+			return link, nil
+		}
+		return updated, nil
+	}
+
+	return link, nil
+}
+
+// mergeScrapedContent builds the update that enriches a newly created link
+// with scraped content. With onlyFillEmpty only empty fields are filled;
+// otherwise the scraped title and text replace the saved ones.
+func mergeScrapedContent(link *models.Link, scrapeResult *scraper.ScrapeResponse, onlyFillEmpty bool) (models.LinkUpdate, bool) {
 	update := models.LinkUpdate{}
 	changed := false
 
-	if scrapeOptions.OnlyFillEmpty {
+	if onlyFillEmpty {
 		// Only update fields that are currently empty
 		if (link.Title == nil || strings.TrimSpace(*link.Title) == "") && scrapeResult.Title != "" {
 			title := scrapeResult.Title
@@ -94,6 +113,12 @@ func (s *LinkService) CreateLinkWithScraping(
 			changed = true
 		}
 
+		if (link.Description == nil || strings.TrimSpace(*link.Description) == "") && scrapeResult.Description != "" {
+			description := scrapeResult.Description
+			update.Description = &description
+			changed = true
+		}
+
 		if (link.Text == nil || strings.TrimSpace(*link.Text) == "") && scrapeResult.Text != "" {
 			text := scrapeResult.Text
 			update.Text = &text
@@ -106,6 +131,12 @@ func (s *LinkService) CreateLinkWithScraping(
 			update.Title = &title
 			changed = true
 		}
+		// A description given when saving is kept; the scraped one only fills a gap
+		if (link.Description == nil || *link.Description == "") && scrapeResult.Description != "" {
+			description := scrapeResult.Description
+			update.Description = &description
+			changed = true
+		}
 		if scrapeResult.Text != "" {
 			text := scrapeResult.Text
 			update.Text = &text
@@ -113,17 +144,7 @@ func (s *LinkService) CreateLinkWithScraping(
 		}
 	}
 
-	// Step 4: Update link with enriched content
-	if changed {
-		updated, err := s.UpdateLink(ctx, link.ID, userID, update)
-		if err != nil {
-			// Log error but return original link
-			return link, nil
-		}
-		return updated, nil
-	}
-
-	return link, nil
+	return update, changed
 }
 
 // EnrichLink enriches an existing link with scraped content
@@ -154,6 +175,11 @@ func (s *LinkService) EnrichLink(
 			update.Title = &title
 			changed = true
 		}
+		if (link.Description == nil || strings.TrimSpace(*link.Description) == "") && scrapeResult.Description != "" {
+			description := scrapeResult.Description
+			update.Description = &description
+			changed = true
+		}
 		if (link.Text == nil || strings.TrimSpace(*link.Text) == "") && scrapeResult.Text != "" {
 			text := scrapeResult.Text
 			update.Text = &text
@@ -165,6 +191,11 @@ func (s *LinkService) EnrichLink(
 			update.Title = &title
 			changed = true
 		}
+		if scrapeResult.Description != "" {
+			description := scrapeResult.Description
+			update.Description = &description
+			changed = true
+		}
 		if scrapeResult.Text != "" {
 			text := scrapeResult.Text
 			update.Text = &text
@@ -0,0 +1,45 @@
+package services
+
+import (
+	"testing"
+
+	"link-mgmt/pkg/models"
+	"link-mgmt/pkg/scraper"
+)
+
+func TestMergeScrapedContentKeepsProvidedDescription(t *testing.T) {
+	scraped := &scraper.ScrapeResponse{Title: "Scraped title", Description: "Scraped description", Text: "Scraped text"}
+	provided := "My own notes"
+	empty := ""
+
+	tests := []struct {
+		name        string
+		description *string
+		want        string
+	}{
+		{name: "provided description is kept", description: &provided, want: ""},
+		{name: "missing description is filled", description: nil, want: "Scraped description"},
+		{name: "empty description is filled", description: &empty, want: "Scraped description"},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			link := &models.Link{URL: "https://example.com", Description: tt.description}
+
+			update, changed := mergeScrapedContent(link, scraped, false)
+			if !changed {
+				t.Fatal("mergeScrapedContent() reported no change")
+			}
+			if update.Title == nil || *update.Title != scraped.Title {
+				t.Errorf("Title = %v, want %q", update.Title, scraped.Title)
+			}
+			got := ""
+			if update.Description != nil {
+				got = *update.Description
+			}
+			if got != tt.want {
+				t.Errorf("Description = %q, want %q", got, tt.want)
+			}
+		})
+	}
+}
@@ -0,0 +1,47 @@
+package utils
+
+import (
+	"net/url"
+	"sort"
+	"strings"
+
+	"link-mgmt/pkg/models"
+)
+
+// DomainCount pairs a domain with the number of links pointing at it
+type DomainCount struct {
+	Domain string
+	Count  int
+}
+
+// DomainFrequency counts links per host name, grouping "www." hosts with their
+// bare domain, and returns the entries sorted by count descending
+func DomainFrequency(links []models.Link) []DomainCount {
+	counts := make(map[string]int)
+	for _, link := range links {
+		u, err := url.Parse(strings.TrimSpace(link.URL))
+		if err != nil {
+			continue
+		}
+		host := strings.TrimPrefix(strings.ToLower(u.Hostname()), "www.")
+		if host == "" {
+			continue
+		}
+		counts[host]++
+	}
+
+	result := make([]DomainCount, 0, len(counts))
+	for domain, count := range counts {
+		result = append(result, DomainCount{Domain: domain, Count: count})
+	}
+
+	// Ties are broken alphabetically so output is stable between runs
+	sort.Slice(result, func(i, j int) bool {
+		if result[i].Count != result[j].Count {
+			return result[i].Count > result[j].Count
+		}
+		return result[i].Domain < result[j].Domain
+	})
+
+	return result
+}
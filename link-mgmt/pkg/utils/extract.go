@@ -0,0 +1,109 @@
+package utils
+
+import (
+	"html"
+	"net/url"
+	"regexp"
+	"strings"
+)
+
+// ExtractedLink is a URL found in a document along with the text it was linked from
+type ExtractedLink struct {
+	URL  string
+	Text string // Link text, empty for bare URLs and autolinks
+}
+
+// Markdown link destinations and bare URLs may contain one level of balanced
+// parentheses, as in Wikipedia URLs like /wiki/Foo_(bar)
+var (
+	htmlDocument   = regexp.MustCompile(`(?i)<!doctype html|<html[\s>]|<body[\s>]`)
+	htmlAnchor     = regexp.MustCompile(`(?is)<a\s[^>]*?href\s*=\s*(?:"([^"]*)"|'([^']*)')[^>]*>(.*?)</a>`)
+	htmlTag        = regexp.MustCompile(`(?s)<[^>]*>`)
+	markdownLink   = regexp.MustCompile(`\[([^\]]*)\]\(\s*(?:<([^>\s]+)>|((?:[^()\s<>]|\([^()\s]*\))+))(?:\s+"[^"]*")?\s*\)`)
+	markdownAuto   = regexp.MustCompile(`<(https?://[^>\s]+)>`)
+	markdownBare   = regexp.MustCompile(`https?://(?:` + bareURLChar + `|\(` + bareURLChar + `*\))+`)
+	whitespaceRuns = regexp.MustCompile(`\s+`)
+)
+
+// bareURLChar is a character allowed in a bare URL outside of parentheses
+const bareURLChar = `[^\s<>()\[\]"'` + "`" + `]`
+
+// trailingPunct is stripped from bare URLs, which usually end a sentence
+const trailingPunct = ".,;:!?"
+
+// ExtractLinks finds the links in a Markdown or HTML document, sniffing which
+// one it is from the content. Only absolute http(s) URLs are returned, so
+// anchors, mailto: and relative links are skipped. Each URL appears once, with
+// the text of its first occurrence.
+func ExtractLinks(content string) []ExtractedLink {
+	var found []ExtractedLink
+	// spans holds the byte ranges of links already found, so bare URLs inside
+	// them are not picked up a second time, possibly cut short
+	var spans [][]int
+	isHTML := htmlDocument.MatchString(content)
+	if !isHTML {
+		for _, m := range markdownLink.FindAllStringSubmatchIndex(content, -1) {
+			var dest string
+			if m[4] >= 0 {
+				dest = content[m[4]:m[5]]
+			} else {
+				dest = content[m[6]:m[7]]
+			}
+			found = append(found, ExtractedLink{URL: dest, Text: content[m[2]:m[3]]})
+			spans = append(spans, m[:2])
+		}
+	}
+
+	// Markdown allows inline HTML, so anchors are collected from both
+	for _, m := range htmlAnchor.FindAllStringSubmatchIndex(content, -1) {
+		var href string
+		if m[2] >= 0 {
+			href = content[m[2]:m[3]]
+		} else {
+			href = content[m[4]:m[5]]
+		}
+		text := html.UnescapeString(htmlTag.ReplaceAllString(content[m[6]:m[7]], ""))
+		found = append(found, ExtractedLink{URL: html.UnescapeString(href), Text: text})
+		spans = append(spans, m[:2])
+	}
+
+	if !isHTML {
+		for _, m := range markdownAuto.FindAllStringSubmatchIndex(content, -1) {
+			found = append(found, ExtractedLink{URL: content[m[2]:m[3]]})
+			spans = append(spans, m[:2])
+		}
+		for _, m := range markdownBare.FindAllStringIndex(content, -1) {
+			if inSpans(spans, m[0], m[1]) {
+				continue
+			}
+			found = append(found, ExtractedLink{URL: strings.TrimRight(content[m[0]:m[1]], trailingPunct)})
+		}
+	}
+
+	seen := make(map[string]bool)
+	var links []ExtractedLink
+	for _, link := range found {
+		link.URL = strings.TrimSpace(link.URL)
+		parsed, err := url.Parse(link.URL)
+		if err != nil || (parsed.Scheme != "http" && parsed.Scheme != "https") || parsed.Host == "" {
+			continue
+		}
+		if seen[link.URL] {
+			continue
+		}
+		seen[link.URL] = true
+		link.Text = strings.TrimSpace(whitespaceRuns.ReplaceAllString(link.Text, " "))
+		links = append(links, link)
+	}
+	return links
+}
+
+// inSpans reports whether the range [start, end) lies within one of spans
+func inSpans(spans [][]int, start, end int) bool {
+	for _, span := range spans {
+		if start >= span[0] && end <= span[1] {
+			return true
+		}
+	}
+	return false
+}
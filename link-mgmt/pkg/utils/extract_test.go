@@ -0,0 +1,97 @@
+package utils
+
+import (
+	"reflect"
+	"testing"
+)
+
+func TestExtractLinks(t *testing.T) {
+	tests := []struct {
+		name    string
+		content string
+		want    []ExtractedLink
+	}{
+		{
+			name:    "markdown link",
+			content: "Read [the docs](https://go.dev/doc) first.",
+			want:    []ExtractedLink{{URL: "https://go.dev/doc", Text: "the docs"}},
+		},
+		{
+			name:    "markdown link with balanced parentheses",
+			content: "See [Foo](https://en.wikipedia.org/wiki/Foo_(bar)) for details.",
+			want:    []ExtractedLink{{URL: "https://en.wikipedia.org/wiki/Foo_(bar)", Text: "Foo"}},
+		},
+		{
+			name:    "markdown link with a title",
+			content: `[Go](https://go.dev "The Go site")`,
+			want:    []ExtractedLink{{URL: "https://go.dev", Text: "Go"}},
+		},
+		{
+			name:    "markdown link with an angle-bracket destination",
+			content: "[Example](<https://example.com/path>)",
+			want:    []ExtractedLink{{URL: "https://example.com/path", Text: "Example"}},
+		},
+		{
+			name:    "autolink",
+			content: "Mail me or visit <https://example.com/auto>.",
+			want:    []ExtractedLink{{URL: "https://example.com/auto"}},
+		},
+		{
+			name:    "bare URL ending a sentence",
+			content: "More at https://example.com/page.",
+			want:    []ExtractedLink{{URL: "https://example.com/page"}},
+		},
+		{
+			name:    "bare URL with balanced parentheses",
+			content: "see https://en.wikipedia.org/wiki/Foo_(bar) for more",
+			want:    []ExtractedLink{{URL: "https://en.wikipedia.org/wiki/Foo_(bar)"}},
+		},
+		{
+			name:    "bare URL inside parentheses",
+			content: "A site (https://example.com/x) in a sentence.",
+			want:    []ExtractedLink{{URL: "https://example.com/x"}},
+		},
+		{
+			name:    "inline HTML anchor in markdown",
+			content: `Try <a href="https://example.com/?a=1&amp;b=2">a <b>bold</b> link</a>.`,
+			want:    []ExtractedLink{{URL: "https://example.com/?a=1&b=2", Text: "a bold link"}},
+		},
+		{
+			name: "mailto, relative and fragment links are skipped",
+			content: "[mail](mailto:me@example.com) [rel](/docs/page) [top](#top) " +
+				"[ftp](ftp://example.com/file) [ok](https://example.com/ok)",
+			want: []ExtractedLink{{URL: "https://example.com/ok", Text: "ok"}},
+		},
+		{
+			name: "duplicates keep the first text",
+			content: "[first](https://example.com/a) and [second](https://example.com/a)\n" +
+				"plus https://example.com/a and <https://example.com/a>",
+			want: []ExtractedLink{{URL: "https://example.com/a", Text: "first"}},
+		},
+		{
+			name:    "link text is collapsed",
+			content: "[multi\n  line   text](https://example.com/m)",
+			want:    []ExtractedLink{{URL: "https://example.com/m", Text: "multi line text"}},
+		},
+		{
+			name: "HTML document uses anchors only",
+			content: "<!DOCTYPE html><html><body>" +
+				"<a href='https://example.com/h'>Home</a> [md](https://example.com/md) https://example.com/bare" +
+				"</body></html>",
+			want: []ExtractedLink{{URL: "https://example.com/h", Text: "Home"}},
+		},
+		{
+			name:    "no links",
+			content: "Just text, no links.",
+			want:    nil,
+		},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			if got := ExtractLinks(tt.content); !reflect.DeepEqual(got, tt.want) {
+				t.Errorf("ExtractLinks() = %+v, want %+v", got, tt.want)
+			}
+		})
+	}
+}
@@ -0,0 +1,63 @@
+package utils
+
+import (
+	"regexp"
+	"strings"
+	"unicode"
+)
+
+// ansiSequence matches CSI sequences (colors, cursor movement) and OSC
+// sequences (window titles, hyperlinks) terminated by BEL or ST
+var ansiSequence = regexp.MustCompile(`\x1b\[[0-?]*[ -/]*[@-~]|\x1b\][^\x07\x1b]*(?:\x07|\x1b\\)`)
+
+// TruncateAtWordBoundary shortens s to at most max characters, cutting at the
+// last word boundary before the limit and appending "…". A single word longer
+// than the limit is cut mid-word. max <= 0 means no limit.
+func TruncateAtWordBoundary(s string, max int) string {
+	runes := []rune(s)
+	if max <= 0 || len(runes) <= max {
+		return s
+	}
+
+	// Leave room for the ellipsis, and back up to a space unless the cut
+	// already falls at the end of a word
+	cut := runes[:max-1]
+	if !unicode.IsSpace(runes[max-1]) {
+		for i := len(cut) - 1; i > 0; i-- {
+			if unicode.IsSpace(cut[i]) {
+				cut = cut[:i]
+				break
+			}
+		}
+	}
+
+	return strings.TrimRightFunc(string(cut), unicode.IsSpace) + "…"
+}
+
+// SanitizeForTerminal makes remotely sourced text safe to print. It removes
+// ANSI escape sequences, C0/C1 control characters and bidi override
+// characters, keeps newlines, and turns tabs into spaces.
+func SanitizeForTerminal(s string) string {
+	s = ansiSequence.ReplaceAllString(s, "")
+
+	return strings.Map(func(r rune) rune {
+		switch {
+		case r == '\n':
+			return r
+		case r == '\t':
+			return ' '
+		case unicode.IsControl(r):
+			return -1
+		case r >= '\u202A' && r <= '\u202E', r >= '\u2066' && r <= '\u2069':
+			// Bidi embeddings, overrides and isolates can reorder what is displayed
+			return -1
+		}
+		return r
+	}, s)
+}
+
+// SanitizeLine is SanitizeForTerminal for single-line output such as table
+// cells and log messages: newlines and runs of whitespace become one space.
+func SanitizeLine(s string) string {
+	return strings.Join(strings.Fields(SanitizeForTerminal(s)), " ")
+}
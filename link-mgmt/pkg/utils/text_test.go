@@ -0,0 +1,86 @@
+package utils
+
+import "testing"
+
+func TestTruncateAtWordBoundary(t *testing.T) {
+	tests := []struct {
+		name string
+		s    string
+		max  int
+		want string
+	}{
+		{name: "shorter than the limit", s: "hello world", max: 20, want: "hello world"},
+		{name: "exactly the limit", s: "hello world", max: 11, want: "hello world"},
+		{name: "cut at a space", s: "the quick brown fox", max: 12, want: "the quick…"},
+		{name: "cut at the end of a word", s: "the quick brown fox", max: 10, want: "the quick…"},
+		{name: "spaces before the cut", s: "hello     world", max: 8, want: "hello…"},
+		{name: "multi-byte text", s: "héllo wörld ünïcode", max: 14, want: "héllo wörld…"},
+		{name: "multi-byte without spaces", s: "日本語のテキストです", max: 5, want: "日本語の…"},
+		{name: "word longer than the limit", s: "supercalifragilistic", max: 8, want: "superca…"},
+		{name: "long first word then more", s: "supercalifragilistic word", max: 8, want: "superca…"},
+		{name: "max 1", s: "hello", max: 1, want: "…"},
+		{name: "max 0 means no limit", s: "hello world", max: 0, want: "hello world"},
+		{name: "negative max means no limit", s: "hello world", max: -5, want: "hello world"},
+		{name: "empty", s: "", max: 5, want: ""},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			if got := TruncateAtWordBoundary(tt.s, tt.max); got != tt.want {
+				t.Errorf("TruncateAtWordBoundary(%q, %d) = %q, want %q", tt.s, tt.max, got, tt.want)
+			}
+		})
+	}
+}
+
+func TestSanitizeForTerminal(t *testing.T) {
+	tests := []struct {
+		name string
+		s    string
+		want string
+	}{
+		{name: "plain text", s: "Hello, world", want: "Hello, world"},
+		{name: "CSI color", s: "\x1b[31mred\x1b[0m text", want: "red text"},
+		{name: "CSI cursor movement", s: "a\x1b[2Ab\x1b[10;20Hc", want: "abc"},
+		{name: "CSI clear screen", s: "\x1b[2J\x1b[Hhome", want: "home"},
+		{name: "OSC title ended by BEL", s: "x\x1b]0;pwned\x07y", want: "xy"},
+		{name: "OSC hyperlink ended by ST", s: "\x1b]8;;https://evil.example\x1b\\click\x1b]8;;\x1b\\", want: "click"},
+		{name: "bare escape", s: "a\x1bb", want: "ab"},
+		{name: "C0 controls", s: "a\x00b\x07c\x08d\re", want: "abcde"},
+		{name: "C1 controls", s: "a\u0085b\u009bc\u009dd", want: "abcd"},
+		{name: "right-to-left override", s: "file\u202etxt.exe", want: "filetxt.exe"},
+		{name: "bidi isolates", s: "a\u2066b\u2069c", want: "abc"},
+		{name: "newlines kept", s: "line one\nline two", want: "line one\nline two"},
+		{name: "tabs become spaces", s: "a\tb", want: "a b"},
+		{name: "multi-byte text kept", s: "héllo 日本語 ✓", want: "héllo 日本語 ✓"},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			if got := SanitizeForTerminal(tt.s); got != tt.want {
+				t.Errorf("SanitizeForTerminal(%q) = %q, want %q", tt.s, got, tt.want)
+			}
+		})
+	}
+}
+
+func TestSanitizeLine(t *testing.T) {
+	tests := []struct {
+		name string
+		s    string
+		want string
+	}{
+		{name: "newlines collapse", s: "first\nsecond\r\nthird", want: "first second third"},
+		{name: "whitespace runs collapse", s: "  a \t\t b  ", want: "a b"},
+		{name: "escapes removed too", s: "\x1b[1mbold\x1b[0m\ntitle", want: "bold title"},
+		{name: "only controls", s: "\x1b[2J\n\x07", want: ""},
+	}
+
+	for _, tt := range tests {
+		t.Run(tt.name, func(t *testing.T) {
+			if got := SanitizeLine(tt.s); got != tt.want {
+				t.Errorf("SanitizeLine(%q) = %q, want %q", tt.s, got, tt.want)
+			}
+		})
+	}
+}
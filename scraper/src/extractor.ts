@@ -8,13 +8,41 @@ export function cleanupText(text: string): string {
   return text.replace(/^[\n\r]+|[\n\r]+$/g, "").trim();
 }
 
+/**
+ * Reads the first non-empty content attribute among the given meta selectors
+ */
+function readMeta(
+  document: JSDOM["window"]["document"],
+  selectors: string[]
+): string {
+  for (const selector of selectors) {
+    const content = document
+      .querySelector(selector)
+      ?.getAttribute("content")
+      ?.trim();
+    if (content) {
+      return content;
+    }
+  }
+  return "";
+}
+
 export async function extractMainContent(
   html: string,
   url: string
 ): Promise<ExtractedContent | null> {
   try {
     const dom = new JSDOM(html, { url });
-    const reader = new Readability(dom.window.document);
+    const document = dom.window.document;
+
+    // Read meta tags before Readability, which mutates the document
+    const ogTitle = readMeta(document, ['meta[property="og:title"]']);
+    const description = readMeta(document, [
+      'meta[property="og:description"]',
+      'meta[name="description"]',
+    ]);
+
+    const reader = new Readability(document);
     const article = reader.parse();
 
     if (!article) {
@@ -26,7 +54,9 @@ export async function extractMainContent(
     }
 
     return {
-      title: article.title || "",
+      // OpenGraph titles are usually cleaner than <title> (no site suffix)
+      title: ogTitle || article.title || "",
+      description,
       text: cleanupText(article.textContent || ""),
     };
   } catch (error) {
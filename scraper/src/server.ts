@@ -231,6 +231,7 @@ async function handleScrape(request: Request): Promise<Response> {
         success: true,
         url,
         title: extracted.title || "",
+        description: extracted.description || "",
         text: extracted.text || "",
         extracted_at: new Date().toISOString(),
       },
@@ -348,6 +349,7 @@ async function handleBatchScrape(request: Request): Promise<Response> {
         results.push({
           url,
           title: extracted.title || "",
+          description: extracted.description || "",
           text: extracted.text || "",
           extracted_at: new Date().toISOString(),
           error: null,
@@ -3,6 +3,7 @@ import { ScrapeErrorType } from "./errors";
 export interface ExtractionResult {
   url: string;
   title: string;
+  description?: string;
   text: string;
   extracted_at: string;
   error: string | null;
@@ -12,6 +13,7 @@ export interface ExtractionResult {
 
 export interface ExtractedContent {
   title: string;
+  description: string;
   text: string;
 }
 
@@ -19,6 +21,7 @@ export interface ScrapeResponse {
   success: boolean;
   url: string;
   title?: string;
+  description?: string;
   text?: string;
   extracted_at?: string;
   error?: string;